@@ -0,0 +1,99 @@
+//! Timing the parse and solve phases of a [`Solution`].
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{Answer, Part, Solution, Unsolved};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Untimed runs before measuring.
+    pub warmup: usize,
+    /// Timed runs per phase.
+    pub samples: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            samples: 50,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Solve { part: Part, variant: &'static str },
+}
+
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub phase: Phase,
+    /// `None` for the parse phase.
+    pub answer: Option<Answer>,
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Measurement {
+    fn new(phase: Phase, answer: Option<Answer>, mut times: Vec<Duration>) -> Self {
+        times.sort();
+        Self {
+            phase,
+            answer,
+            samples: times.len(),
+            min: times[0],
+            median: times[times.len() / 2],
+            mean: times.iter().sum::<Duration>() / times.len() as u32,
+        }
+    }
+}
+
+fn time<T>(options: Options, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+
+    (0..options.samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect()
+}
+
+/// Times parsing, then every variant of every solved part.
+pub fn run<S: Solution>(input: &str, options: Options) -> color_eyre::Result<Vec<Measurement>> {
+    let parsed = S::parse(input)?;
+    let mut measurements = vec![Measurement::new(
+        Phase::Parse,
+        None,
+        time(options, || S::parse(input)),
+    )];
+
+    for variant in S::variants() {
+        let answer = match (variant.solve)(&parsed) {
+            Ok(answer) => answer,
+            Err(e) if e.downcast_ref::<Unsolved>().is_some() => continue,
+            Err(e) => return Err(e.wrap_err(format!("part {} ({})", variant.part, variant.name))),
+        };
+
+        measurements.push(Measurement::new(
+            Phase::Solve {
+                part: variant.part,
+                variant: variant.name,
+            },
+            Some(answer),
+            time(options, || (variant.solve)(&parsed)),
+        ));
+    }
+
+    Ok(measurements)
+}
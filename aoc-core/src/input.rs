@@ -0,0 +1,188 @@
+//! Loading puzzle inputs at runtime, from a file or from stdin.
+
+use std::{
+    fmt,
+    io::{self, BufRead, BufReader, Read},
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// Where a puzzle input comes from. `-` on the command line means stdin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl FromStr for Source {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Source::Stdin,
+            path => Source::Path(path.into()),
+        })
+    }
+}
+
+impl From<PathBuf> for Source {
+    fn from(path: PathBuf) -> Self {
+        Source::Path(path)
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum InputError {
+    #[error("{origin}: no such file")]
+    NotFound { origin: Source },
+
+    #[error("{origin}: could not be read")]
+    Io {
+        origin: Source,
+        #[source]
+        error: io::Error,
+    },
+
+    #[error("{origin}: invalid UTF-8 at line {line}, column {column}")]
+    NotUtf8 {
+        origin: Source,
+        line: usize,
+        column: usize,
+    },
+
+    #[error("{origin}: input is empty")]
+    Empty { origin: Source },
+
+    #[error("{origin}: line {line} ends with \\r\\n, expected \\n")]
+    CarriageReturn { origin: Source, line: usize },
+
+    #[error("{origin}: {count} blank line(s) after the last line")]
+    TrailingBlankLines { origin: Source, count: usize },
+}
+
+impl Source {
+    /// Reads the whole input and checks it with [`check`].
+    pub fn load(&self) -> Result<String, InputError> {
+        let bytes = self.read().map_err(|error| self.io_error(error))?;
+
+        let text = String::from_utf8(bytes).map_err(|e| {
+            let valid = &e.as_bytes()[..e.utf8_error().valid_up_to()];
+            let (line, column) = position(valid);
+            InputError::NotUtf8 {
+                origin: self.clone(),
+                line,
+                column,
+            }
+        })?;
+
+        check(&text).map_err(|quirk| quirk.at(self.clone()))?;
+        Ok(text)
+    }
+
+    /// Opens the input to be read bit by bit, for inputs too big to
+    /// [`load`](Self::load). Nothing is checked.
+    pub fn reader(&self) -> Result<Box<dyn BufRead>, InputError> {
+        Ok(match self {
+            Source::Path(path) => Box::new(BufReader::new(
+                std::fs::File::open(path).map_err(|error| self.io_error(error))?,
+            )),
+            Source::Stdin => Box::new(io::stdin().lock()),
+        })
+    }
+
+    pub fn io_error(&self, error: io::Error) -> InputError {
+        match error.kind() {
+            io::ErrorKind::NotFound => InputError::NotFound {
+                origin: self.clone(),
+            },
+            _ => InputError::Io {
+                origin: self.clone(),
+                error,
+            },
+        }
+    }
+
+    fn read(&self) -> io::Result<Vec<u8>> {
+        match self {
+            Source::Path(path) => std::fs::read(path),
+            Source::Stdin => {
+                let mut bytes = Vec::new();
+                io::stdin().lock().read_to_end(&mut bytes)?;
+                Ok(bytes)
+            }
+        }
+    }
+}
+
+/// Something about an input's line endings that the solvers would
+/// otherwise silently misread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quirk {
+    Empty,
+    CarriageReturn { line: usize },
+    TrailingBlankLines { count: usize },
+}
+
+impl Quirk {
+    fn at(self, origin: Source) -> InputError {
+        match self {
+            Quirk::Empty => InputError::Empty { origin },
+            Quirk::CarriageReturn { line } => InputError::CarriageReturn { origin, line },
+            Quirk::TrailingBlankLines { count } => InputError::TrailingBlankLines { origin, count },
+        }
+    }
+}
+
+/// Accepts inputs with `\n` line endings, with or without a final newline.
+pub fn check(text: &str) -> Result<(), Quirk> {
+    if text.is_empty() {
+        return Err(Quirk::Empty);
+    }
+
+    if let Some(offset) = text.find('\r') {
+        let (line, _) = position(&text.as_bytes()[..offset]);
+        return Err(Quirk::CarriageReturn { line });
+    }
+
+    let newlines = text.len() - text.trim_end_matches('\n').len();
+    if newlines > 1 {
+        return Err(Quirk::TrailingBlankLines {
+            count: newlines - 1,
+        });
+    }
+
+    Ok(())
+}
+
+/// 1-based line and column of the byte right after `prefix`.
+fn position(prefix: &[u8]) -> (usize, usize) {
+    let line = prefix.iter().filter(|&&b| b == b'\n').count() + 1;
+    let column = prefix.iter().rev().take_while(|&&b| b != b'\n').count() + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, Quirk};
+
+    #[test]
+    fn line_endings() {
+        assert_eq!(check("1\n2"), Ok(()));
+        assert_eq!(check("1\n2\n"), Ok(()));
+        assert_eq!(check(""), Err(Quirk::Empty));
+        assert_eq!(check("1\n2\r\n"), Err(Quirk::CarriageReturn { line: 2 }));
+        assert_eq!(
+            check("1\n2\n\n\n"),
+            Err(Quirk::TrailingBlankLines { count: 2 })
+        );
+    }
+}
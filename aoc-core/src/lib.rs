@@ -0,0 +1,9 @@
+//! Pieces shared by every day's solution and the `aoc` runner.
+
+pub mod bench;
+pub mod input;
+pub mod parse;
+mod solution;
+pub mod trace;
+
+pub use solution::{solve, Answer, Part, Solution, Solved, Unsolved, Variant};
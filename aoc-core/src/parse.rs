@@ -0,0 +1,297 @@
+//! nom helpers for line-oriented puzzle inputs, with errors that point at
+//! the offending line and column.
+//!
+//! Parsers return [`PResult`], whose [`Error`] remembers what was expected
+//! where. Once parsing fails, [`ParseError::new`] turns it into something
+//! fit for humans:
+//!
+//! ```text
+//! line 2, column 8: expected "from"
+//!   |
+//! 2 | move 1 frm 2 to 1
+//!   |        ^
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use nom::{
+    character::complete::{char, digit1},
+    combinator::{map_res, opt, recognize},
+    error::{ContextError, ErrorKind, FromExternalError},
+    multi::separated_list1,
+    sequence::pair,
+    IResult,
+};
+
+pub type PResult<'a, T> = IResult<&'a str, T, Error<'a>>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    /// Literal text, see [`token`].
+    Token(&'static str),
+    /// A description, from [`nom::error::context`].
+    Label(&'static str),
+    Char(char),
+    Kind(ErrorKind),
+    EndOfLine,
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Token(token) => write!(f, "{token:?}"),
+            Expected::Label(label) => write!(f, "{label}"),
+            Expected::Char(c) => write!(f, "{c:?}"),
+            Expected::Kind(kind) => write!(f, "{}", kind.description()),
+            Expected::EndOfLine => write!(f, "end of line"),
+        }
+    }
+}
+
+/// Where a parser gave up, and what it would have accepted there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error<'a> {
+    pub input: &'a str,
+    pub expected: Vec<Expected>,
+}
+
+impl<'a> Error<'a> {
+    pub fn new(input: &'a str, expected: Expected) -> Self {
+        Self {
+            input,
+            expected: vec![expected],
+        }
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Error<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Self::new(input, Expected::Kind(kind))
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        // the innermost error is the one that says what was actually wrong
+        other
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        Self::new(input, Expected::Char(c))
+    }
+
+    fn or(mut self, other: Self) -> Self {
+        // keep the alternative that got furthest, or all of them on a tie
+        match self.input.len().cmp(&other.input.len()) {
+            std::cmp::Ordering::Less => self,
+            std::cmp::Ordering::Greater => other,
+            std::cmp::Ordering::Equal => {
+                for expected in other.expected {
+                    if !self.expected.contains(&expected) {
+                        self.expected.push(expected);
+                    }
+                }
+                self
+            }
+        }
+    }
+}
+
+impl<'a> ContextError<&'a str> for Error<'a> {
+    fn add_context(input: &'a str, ctx: &'static str, other: Self) -> Self {
+        // a label only beats nom's generic error kinds for the same spot
+        let generic = other
+            .expected
+            .iter()
+            .all(|expected| matches!(expected, Expected::Kind(_)));
+        if generic && other.input == input {
+            Self::new(input, Expected::Label(ctx))
+        } else {
+            other
+        }
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Error<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _e: E) -> Self {
+        Self::new(input, Expected::Kind(kind))
+    }
+}
+
+/// A parse failure located in its input.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub struct ParseError {
+    /// 1-based
+    pub line: usize,
+    /// 1-based, in characters
+    pub column: usize,
+    pub expected: String,
+    /// The whole offending line.
+    pub snippet: String,
+}
+
+impl ParseError {
+    /// Locates `error` in `source`, which must be the text (or a text
+    /// containing the text) that was handed to the parser.
+    pub fn new(source: &str, error: Error<'_>) -> Self {
+        Self::at(source, error.input, expected_list(&error.expected))
+    }
+
+    /// An error at the start of `at`, a slice of `source`, for problems
+    /// found after parsing.
+    pub fn at(source: &str, at: &str, expected: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(source.as_ptr() as usize)
+            .filter(|&offset| offset <= source.len())
+            .unwrap_or(0);
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+            expected: expected.into(),
+            snippet: source[line_start..line_end].to_owned(),
+        }
+    }
+}
+
+fn expected_list(expected: &[Expected]) -> String {
+    let mut list = String::new();
+    for (i, e) in expected.iter().enumerate() {
+        if i > 0 {
+            list.push_str(if i + 1 == expected.len() {
+                " or "
+            } else {
+                ", "
+            });
+        }
+        list.push_str(&e.to_string());
+    }
+    list
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{gutter} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+/// Matches `token` exactly, reporting it by name when it's missing.
+pub fn token<'a>(token: &'static str) -> impl FnMut(&'a str) -> PResult<'a, &'a str> {
+    move |i: &'a str| match i.strip_prefix(token) {
+        Some(rest) => Ok((rest, &i[..token.len()])),
+        None => Err(nom::Err::Error(Error::new(i, Expected::Token(token)))),
+    }
+}
+
+/// An optionally negative decimal number.
+pub fn number<'a, T: FromStr>(i: &'a str) -> PResult<'a, T> {
+    nom::error::context(
+        "number",
+        map_res(recognize(pair(opt(char('-')), digit1)), str::parse),
+    )(i)
+}
+
+/// Numbers separated by `separator`, e.g. `1,2,3`.
+pub fn numbers<'a, T: FromStr>(
+    separator: &'static str,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<T>> {
+    separated_list1(token(separator), number)
+}
+
+/// Runs `parser` on a single line, which it must consume entirely.
+pub fn line<'a, O>(
+    line: &'a str,
+    mut parser: impl FnMut(&'a str) -> PResult<'a, O>,
+) -> Result<O, Error<'a>> {
+    match parser(line) {
+        Ok(("", output)) => Ok(output),
+        Ok((rest, _)) => Err(Error::new(rest, Expected::EndOfLine)),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(e),
+        Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers never ask for more input"),
+    }
+}
+
+/// Runs `parser` on every line of `input`.
+pub fn lines<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> PResult<'a, O>,
+) -> Result<Vec<O>, Error<'a>> {
+    input.lines().map(|l| line(l, &mut parser)).collect()
+}
+
+/// Splits `input` into runs of lines separated by blank lines.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split("\n\n")
+        .map(|section| section.trim_end_matches('\n'))
+        .filter(|section| !section.is_empty())
+}
+
+/// [`lines`], with the error located in `input`.
+pub fn parse_lines<'a, O>(
+    input: &'a str,
+    parser: impl FnMut(&'a str) -> PResult<'a, O>,
+) -> Result<Vec<O>, ParseError> {
+    lines(input, parser).map_err(|e| ParseError::new(input, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{branch::alt, sequence::preceded};
+
+    use super::{numbers, parse_lines, token, PResult, ParseError};
+
+    fn parse_move(i: &str) -> PResult<'_, (u32, u32)> {
+        let (i, from) = preceded(token("move "), super::number)(i)?;
+        let (i, to) = preceded(alt((token(" to "), token(" onto "))), super::number)(i)?;
+        Ok((i, (from, to)))
+    }
+
+    #[test]
+    fn positioned_errors() {
+        let input = "move 1 to 2\nmove 3 into 4\n";
+        let error = parse_lines(input, parse_move).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 2,
+                column: 7,
+                expected: r#"" to " or " onto ""#.into(),
+                snippet: "move 3 into 4".into(),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: expected \" to \" or \" onto \"\n  |\n2 | move 3 into 4\n  |       ^"
+        );
+
+        let error = parse_lines("move x to 2", parse_move).unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (6, "number"));
+
+        let error = parse_lines("move 1 to 2 now", parse_move).unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (12, "end of line"));
+    }
+
+    #[test]
+    fn number_lists() {
+        assert_eq!(
+            parse_lines("1,-2,3", numbers::<i32>(",")).unwrap(),
+            vec![vec![1, -2, 3]]
+        );
+        assert_eq!(
+            super::sections("a\nb\n\nc\n").collect::<Vec<_>>(),
+            vec!["a\nb", "c"]
+        );
+    }
+}
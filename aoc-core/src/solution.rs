@@ -0,0 +1,153 @@
+//! The interface every day implements, so the runner can treat them alike.
+
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// A puzzle answer: most are numbers, some (like day 5's crate tops) text.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::Number(n)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::Number(n.into())
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Number(n as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl TryFrom<u8> for Part {
+    type Error = color_eyre::Report;
+
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
+        match n {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(color_eyre::eyre::eyre!("there is no part {n}")),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Returned by parts nobody has solved yet.
+#[derive(Debug, thiserror::Error)]
+#[error("not solved yet")]
+pub struct Unsolved;
+
+/// One implementation of a part. Days with competing implementations list
+/// them all in [`Solution::variants`] so they can be benchmarked side by side.
+pub struct Variant<I> {
+    pub part: Part,
+    pub name: &'static str,
+    pub solve: fn(&I) -> color_eyre::Result<Answer>,
+}
+
+pub trait Solution {
+    /// The puzzle input, parsed once and shared by both parts.
+    type Input;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> color_eyre::Result<Answer>;
+
+    fn part2(_input: &Self::Input) -> color_eyre::Result<Answer> {
+        Err(Unsolved.into())
+    }
+
+    /// Every implementation of each part; by default just `part1` and
+    /// `part2`.
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant {
+                part: Part::One,
+                name: "default",
+                solve: Self::part1,
+            },
+            Variant {
+                part: Part::Two,
+                name: "default",
+                solve: Self::part2,
+            },
+        ]
+    }
+}
+
+/// An answer, along with how long it took to get there.
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Parses `input` and solves one part of it.
+pub fn solve<S: Solution>(input: &str, part: Part) -> color_eyre::Result<Solved> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        Part::One => S::part1(&input),
+        Part::Two => S::part2(&input),
+    }?;
+
+    Ok(Solved {
+        answer,
+        parse_time,
+        solve_time: start.elapsed(),
+    })
+}
@@ -0,0 +1,146 @@
+use std::{fmt, ops};
+
+/// A cell of a bounded [`Grid`](crate::Grid). `y` grows downwards.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GridCoord {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl fmt::Debug for GridCoord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(usize, usize)> for GridCoord {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl GridCoord {
+    /// Moves by `delta`, or `None` when that would go below zero.
+    pub fn offset(self, delta: GridPos) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add_signed(delta.x as isize)?,
+            y: self.y.checked_add_signed(delta.y as isize)?,
+        })
+    }
+}
+
+/// A cell of an unbounded [`SparseGrid`](crate::SparseGrid), or an offset
+/// between two cells of any grid. `y` grows downwards.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GridPos {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl fmt::Debug for GridPos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(i32, i32)> for GridPos {
+    fn from((x, y): (i32, i32)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<GridCoord> for GridPos {
+    fn from(coord: GridCoord) -> Self {
+        Self {
+            x: coord.x as i32,
+            y: coord.y as i32,
+        }
+    }
+}
+
+impl GridPos {
+    pub const UP: Self = Self { x: 0, y: -1 };
+    pub const DOWN: Self = Self { x: 0, y: 1 };
+    pub const LEFT: Self = Self { x: -1, y: 0 };
+    pub const RIGHT: Self = Self { x: 1, y: 0 };
+
+    /// Offsets to the orthogonal neighbours.
+    pub const NEIGHBOURS4: [Self; 4] = [Self::UP, Self::RIGHT, Self::DOWN, Self::LEFT];
+
+    /// Offsets to the orthogonal and diagonal neighbours.
+    pub const NEIGHBOURS8: [Self; 8] = [
+        Self { x: 0, y: -1 },
+        Self { x: 1, y: -1 },
+        Self { x: 1, y: 0 },
+        Self { x: 1, y: 1 },
+        Self { x: 0, y: 1 },
+        Self { x: -1, y: 1 },
+        Self { x: -1, y: 0 },
+        Self { x: -1, y: -1 },
+    ];
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Self::NEIGHBOURS4.into_iter().map(move |delta| self + delta)
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Self::NEIGHBOURS8.into_iter().map(move |delta| self + delta)
+    }
+
+    /// Each component clamped to -1, 0 or 1.
+    pub fn signum(self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+
+    pub fn manhattan(self) -> u32 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+}
+
+impl ops::Add for GridPos {
+    type Output = GridPos;
+
+    fn add(self, other: Self) -> Self::Output {
+        GridPos {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl ops::AddAssign for GridPos {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl ops::Sub for GridPos {
+    type Output = GridPos;
+
+    fn sub(self, other: Self) -> Self::Output {
+        GridPos {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl ops::SubAssign for GridPos {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl ops::Mul<i32> for GridPos {
+    type Output = GridPos;
+
+    fn mul(self, n: i32) -> Self::Output {
+        GridPos {
+            x: self.x * n,
+            y: self.y * n,
+        }
+    }
+}
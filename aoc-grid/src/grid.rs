@@ -0,0 +1,243 @@
+use std::fmt;
+
+use crate::{GridCoord, GridPos};
+
+/// A dense grid of `width * height` cells, stored row by row.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    data: Vec<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError<E> {
+    #[error("grid has no rows")]
+    Empty,
+    #[error("row {row} is {len} cells wide, expected {width}")]
+    Ragged {
+        row: usize,
+        len: usize,
+        width: usize,
+    },
+    #[error("invalid cell {cell:?} at {coord:?}: {error}")]
+    Cell {
+        coord: GridCoord,
+        cell: char,
+        error: E,
+    },
+}
+
+impl<T> Grid<T>
+where
+    T: Default + Clone,
+{
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            data: vec![T::default(); width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from one line of text per row, converting every
+    /// character with `cell`. All rows must be the same width.
+    pub fn parse<E>(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError<E>> {
+        let mut width = None;
+        let mut height = 0;
+        let mut data = Vec::new();
+
+        for (y, line) in input.lines().enumerate() {
+            let before = data.len();
+            for (x, c) in line.chars().enumerate() {
+                let value = cell(c).map_err(|error| ParseError::Cell {
+                    coord: GridCoord { x, y },
+                    cell: c,
+                    error,
+                })?;
+                data.push(value);
+            }
+
+            let len = data.len() - before;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(ParseError::Ragged { row: y, len, width })
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        let width = width.filter(|&w| w > 0).ok_or(ParseError::Empty)?;
+        Ok(Self {
+            width,
+            height,
+            data,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, coord: GridCoord) -> bool {
+        coord.x < self.width && coord.y < self.height
+    }
+
+    pub fn cell(&self, coord: GridCoord) -> Option<&T> {
+        if !self.in_bounds(coord) {
+            return None;
+        }
+        Some(&self.data[coord.y * self.width + coord.x])
+    }
+
+    pub fn cell_mut(&mut self, coord: GridCoord) -> Option<&mut T> {
+        if !self.in_bounds(coord) {
+            return None;
+        }
+        Some(&mut self.data[coord.y * self.width + coord.x])
+    }
+
+    /// Every coordinate, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = GridCoord> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| GridCoord { x, y }))
+    }
+
+    /// Every cell along with its coordinate, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (GridCoord, &T)> {
+        self.coords().zip(self.data.iter())
+    }
+
+    /// Row `y`, or nothing if it's past the bottom of the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        if y >= self.height {
+            return &[];
+        }
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.width.max(1))
+    }
+
+    /// Column `x`, or nothing if it's past the right edge of the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        self.data
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Orthogonal neighbours of `coord` that are inside the grid.
+    pub fn neighbours4(&self, coord: GridCoord) -> impl Iterator<Item = GridCoord> + '_ {
+        self.neighbours(coord, &GridPos::NEIGHBOURS4)
+    }
+
+    /// Orthogonal and diagonal neighbours of `coord` that are inside the grid.
+    pub fn neighbours8(&self, coord: GridCoord) -> impl Iterator<Item = GridCoord> + '_ {
+        self.neighbours(coord, &GridPos::NEIGHBOURS8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        coord: GridCoord,
+        deltas: &'static [GridPos],
+    ) -> impl Iterator<Item = GridCoord> + 'a {
+        deltas
+            .iter()
+            .filter_map(move |&delta| coord.offset(delta))
+            .filter(|&c| self.in_bounds(c))
+    }
+
+    /// The cells met walking from `from` (excluded) by steps of `delta`
+    /// until the edge of the grid. A zero `delta` would never get there, so
+    /// its ray is empty.
+    pub fn ray(&self, from: GridCoord, delta: GridPos) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            coord: (delta != GridPos::default()).then_some(from),
+            delta,
+        }
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(GridCoord, &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            data: self.iter().map(|(coord, cell)| f(coord, cell)).collect(),
+        }
+    }
+
+    /// Pairs up the cells of two grids, or `None` if their sizes differ.
+    pub fn zip<'a, U>(&'a self, other: &'a Grid<U>) -> Option<Grid<(&'a T, &'a U)>> {
+        if (self.width, self.height) != (other.width, other.height) {
+            return None;
+        }
+        Some(Grid {
+            width: self.width,
+            height: self.height,
+            data: self.data.iter().zip(other.data.iter()).collect(),
+        })
+    }
+}
+
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    coord: Option<GridCoord>,
+    delta: GridPos,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (GridCoord, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let coord = self.coord?.offset(self.delta);
+        let cell = coord.and_then(|coord| self.grid.cell(coord));
+        self.coord = cell.and(coord);
+        Some((coord?, cell?))
+    }
+}
+
+impl<T> fmt::Display for Grid<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> fmt::Debug for Grid<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{row:?}")?;
+        }
+        Ok(())
+    }
+}
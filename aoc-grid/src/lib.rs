@@ -0,0 +1,84 @@
+//! Two-dimensional grids for puzzles laid out on a map.
+//!
+//! [`Grid`] is a dense, bounded grid indexed by [`GridCoord`] (`usize`),
+//! parsed from text or built up cell by cell. [`SparseGrid`] is an unbounded
+//! grid indexed by [`GridPos`] (`i32`), which also doubles as the offset
+//! type for moving around either kind of grid.
+
+mod coord;
+mod grid;
+mod sparse;
+
+pub use coord::{GridCoord, GridPos};
+pub use grid::{Grid, ParseError, Ray};
+pub use sparse::SparseGrid;
+
+#[cfg(test)]
+mod tests {
+    use crate::{Grid, GridCoord, GridPos, SparseGrid};
+
+    fn digits() -> Grid<u32> {
+        Grid::parse("123\n456\n", |c| c.to_digit(10).ok_or("not a digit")).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.cell((2, 1).into()), Some(&6));
+        assert_eq!(grid.to_string(), "123\n456\n");
+        assert!(Grid::parse("12\n3\n", |c| c.to_digit(10).ok_or(())).is_err());
+    }
+
+    #[test]
+    fn rows_columns_rays() {
+        let grid = digits();
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        let ray: Vec<_> = grid
+            .ray((0, 0).into(), GridPos::RIGHT)
+            .map(|(_, &cell)| cell)
+            .collect();
+        assert_eq!(ray, vec![2, 3]);
+        assert_eq!(grid.ray((0, 0).into(), GridPos::UP).count(), 0);
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let grid = digits();
+        assert_eq!(grid.column(4).count(), 0);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.row(2), &[] as &[u32]);
+        assert_eq!(grid.ray((1, 1).into(), GridPos::default()).count(), 0);
+
+        let empty = Grid::<u32>::new(0, 2);
+        assert_eq!(empty.column(0).count(), 0);
+        assert_eq!(empty.row(1), &[] as &[u32]);
+        assert_eq!(empty.columns().count(), 0);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = digits();
+        let corner: Vec<GridCoord> = grid.neighbours4((0, 0).into()).collect();
+        assert_eq!(corner, vec![(1, 0).into(), (0, 1).into()]);
+        assert_eq!(grid.neighbours8((1, 0).into()).count(), 5);
+    }
+
+    #[test]
+    fn map_zip() {
+        let grid = digits();
+        let doubled = grid.map(|_, &n| n * 2);
+        let sums = grid.zip(&doubled).unwrap().map(|_, (a, b)| *a + *b);
+        assert_eq!(sums.to_string(), "369\n121518\n");
+    }
+
+    #[test]
+    fn sparse() {
+        let mut grid = SparseGrid::parse("#.\n.#\n", |c| (c == '#').then_some('#'));
+        grid.insert(GridPos { x: -1, y: 0 }, '#');
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.to_string(), "##.\n..#\n");
+        assert_eq!(grid.neighbours8(GridPos::default()).count(), 2);
+    }
+}
@@ -0,0 +1,183 @@
+use std::{
+    collections::{hash_map, HashMap},
+    fmt,
+};
+
+use crate::GridPos;
+
+/// An unbounded grid that only stores the cells that were set, for puzzles
+/// that wander off in any direction.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<GridPos, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a grid from one line of text per row, with the first
+    /// character of the first line at (0, 0). Characters for which `cell`
+    /// returns `None` are left unset.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Self {
+        let cells = input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(move |(x, c)| (GridPos::from((x as i32, y as i32)), c))
+            })
+            .filter_map(|(pos, c)| Some((pos, cell(c)?)))
+            .collect();
+        Self { cells }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn cell(&self, pos: GridPos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn cell_mut(&mut self, pos: GridPos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: GridPos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Sets a cell, returning its previous value.
+    pub fn insert(&mut self, pos: GridPos, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: GridPos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn entry(&mut self, pos: GridPos) -> hash_map::Entry<'_, GridPos, T> {
+        self.cells.entry(pos)
+    }
+
+    /// Set cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (GridPos, &T)> {
+        self.cells.iter().map(|(&pos, cell)| (pos, cell))
+    }
+
+    /// Smallest and largest corners of the rectangle holding every set
+    /// cell, or `None` if the grid is empty.
+    pub fn bounds(&self) -> Option<(GridPos, GridPos)> {
+        let mut positions = self.cells.keys();
+        let first = *positions.next()?;
+        Some(positions.fold((first, first), |(min, max), pos| {
+            (
+                GridPos {
+                    x: min.x.min(pos.x),
+                    y: min.y.min(pos.y),
+                },
+                GridPos {
+                    x: max.x.max(pos.x),
+                    y: max.y.max(pos.y),
+                },
+            )
+        }))
+    }
+
+    /// Set orthogonal neighbours of `pos`.
+    pub fn neighbours4(&self, pos: GridPos) -> impl Iterator<Item = (GridPos, &T)> {
+        pos.neighbours4()
+            .filter_map(|n| Some((n, self.cells.get(&n)?)))
+    }
+
+    /// Set orthogonal and diagonal neighbours of `pos`.
+    pub fn neighbours8(&self, pos: GridPos) -> impl Iterator<Item = (GridPos, &T)> {
+        pos.neighbours8()
+            .filter_map(|n| Some((n, self.cells.get(&n)?)))
+    }
+
+    /// The cells met walking from `from` (excluded) by steps of `delta`,
+    /// stopping at the first unset one.
+    pub fn ray(&self, from: GridPos, delta: GridPos) -> impl Iterator<Item = (GridPos, &T)> {
+        (1..).map_while(move |i| {
+            let pos = from + delta * i;
+            Some((pos, self.cells.get(&pos)?))
+        })
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(GridPos, &T) -> U) -> SparseGrid<U> {
+        SparseGrid {
+            cells: self.iter().map(|(pos, cell)| (pos, f(pos, cell))).collect(),
+        }
+    }
+
+    /// Pairs up the cells set in both grids.
+    pub fn zip<'a, U>(&'a self, other: &'a SparseGrid<U>) -> SparseGrid<(&'a T, &'a U)> {
+        SparseGrid {
+            cells: self
+                .iter()
+                .filter_map(|(pos, a)| Some((pos, (a, other.cell(pos)?))))
+                .collect(),
+        }
+    }
+}
+
+impl<T> FromIterator<(GridPos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (GridPos, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<(GridPos, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (GridPos, T)>>(&mut self, iter: I) {
+        self.cells.extend(iter)
+    }
+}
+
+/// Renders the bounding rectangle, with `.` for unset cells.
+impl<T> fmt::Display for SparseGrid<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                match self.cell(GridPos { x, y }) {
+                    Some(cell) => write!(f, "{cell}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> fmt::Debug for SparseGrid<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.cells.iter()).finish()
+    }
+}
@@ -0,0 +1,68 @@
+//! Known-correct answers, kept in `answers.toml` at the workspace root.
+
+use std::{collections::BTreeMap, path::PathBuf};
+
+use aoc_core::{Answer, Part};
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Serialize, Deserialize)]
+struct Recorded {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<Answer>,
+}
+
+impl Recorded {
+    fn part_mut(&mut self, part: Part) -> &mut Option<Answer> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+/// Answers keyed by day (`day01`), then input file name, then part.
+#[derive(Default, Serialize, Deserialize)]
+pub(crate) struct Answers(BTreeMap<String, BTreeMap<String, Recorded>>);
+
+impl Answers {
+    pub(crate) fn path() -> PathBuf {
+        crate::workspace_root().join("answers.toml")
+    }
+
+    pub(crate) fn load() -> color_eyre::Result<Self> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(toml::from_str(&fs_err::read_to_string(path)?)?)
+    }
+
+    pub(crate) fn save(&self) -> color_eyre::Result<()> {
+        fs_err::write(Self::path(), toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub(crate) fn get(&self, day: u8, input: &str, part: Part) -> Option<&Answer> {
+        let recorded = self.0.get(&key(day))?.get(input)?;
+        match part {
+            Part::One => recorded.part1.as_ref(),
+            Part::Two => recorded.part2.as_ref(),
+        }
+    }
+
+    pub(crate) fn insert(&mut self, day: u8, input: &str, part: Part, answer: Answer) {
+        let recorded = self
+            .0
+            .entry(key(day))
+            .or_default()
+            .entry(input.to_owned())
+            .or_default();
+        *recorded.part_mut(part) = Some(answer);
+    }
+}
+
+fn key(day: u8) -> String {
+    format!("day{day:02}")
+}
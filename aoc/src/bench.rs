@@ -0,0 +1,141 @@
+//! `aoc bench`: times every day's parse and solve phases, comparing
+//! alternative implementations of the same part.
+
+use std::path::Path;
+
+use aoc_core::{
+    bench::{Measurement, Options, Phase},
+    input::Source,
+};
+use serde::Serialize;
+
+use crate::days::Day;
+
+/// One line of the JSON report.
+#[derive(Serialize)]
+struct Row {
+    day: u8,
+    input: String,
+    /// `parse`, `part1` or `part2`
+    phase: String,
+    variant: Option<&'static str>,
+    answer: Option<String>,
+    samples: usize,
+    min_ns: u128,
+    median_ns: u128,
+    mean_ns: u128,
+    /// Median relative to the fastest variant of the same part.
+    relative: Option<f64>,
+    /// Whether the answer matches the first variant of the same part.
+    agrees: bool,
+}
+
+pub(crate) fn bench(
+    days: &[&Day],
+    input: Option<Source>,
+    options: Options,
+    json: Option<&Path>,
+) -> color_eyre::Result<()> {
+    let mut results = Vec::new();
+    for day in days {
+        let source = match &input {
+            Some(source) => source.clone(),
+            None if day.default_input().exists() => day.default_input().into(),
+            None => {
+                eprintln!("day {}: no input.txt, skipping", day.number);
+                continue;
+            }
+        };
+        let text = source.load()?;
+        let measurements = (day.bench)(&text, options)?;
+        results.push((day.number, source.to_string(), measurements));
+    }
+
+    let rows: Vec<_> = results
+        .iter()
+        .flat_map(|(day, input, measurements)| rows(*day, input, measurements))
+        .collect();
+
+    print_table(&rows);
+
+    if let Some(path) = json {
+        fs_err::write(path, serde_json::to_string_pretty(&rows)?)?;
+    }
+
+    Ok(())
+}
+
+fn rows(day: u8, input: &str, measurements: &[Measurement]) -> Vec<Row> {
+    measurements
+        .iter()
+        .map(|m| {
+            let (phase, variant, relative, agrees) = match m.phase {
+                Phase::Parse => ("parse".to_owned(), None, None, true),
+                Phase::Solve { part, variant } => {
+                    let same_part = || {
+                        measurements.iter().filter(move |other| {
+                            matches!(other.phase, Phase::Solve { part: p, .. } if p == part)
+                        })
+                    };
+                    let fastest = same_part().map(|other| other.median).min().unwrap();
+                    let first = same_part().next().unwrap();
+                    (
+                        format!("part{part}"),
+                        Some(variant),
+                        Some(m.median.as_secs_f64() / fastest.as_secs_f64().max(f64::EPSILON)),
+                        first.answer == m.answer,
+                    )
+                }
+            };
+
+            Row {
+                day,
+                input: input.to_owned(),
+                phase,
+                variant,
+                answer: m.answer.as_ref().map(ToString::to_string),
+                samples: m.samples,
+                min_ns: m.min.as_nanos(),
+                median_ns: m.median.as_nanos(),
+                mean_ns: m.mean.as_nanos(),
+                relative,
+                agrees,
+            }
+        })
+        .collect()
+}
+
+fn print_table(rows: &[Row]) {
+    println!(
+        "{:>3}  {:<6} {:<14} {:>10} {:>10} {:>10} {:>8}  answer",
+        "day", "phase", "variant", "median", "min", "mean", "relative"
+    );
+    for row in rows {
+        let relative = row.relative.map(|r| format!("{r:.2}x")).unwrap_or_default();
+        let answer = match (&row.answer, row.agrees) {
+            (Some(answer), true) => answer.clone(),
+            (Some(answer), false) => format!("{answer} (DISAGREES)"),
+            (None, _) => String::new(),
+        };
+
+        println!(
+            "{:>3}  {:<6} {:<14} {:>10} {:>10} {:>10} {:>8}  {answer}",
+            row.day,
+            row.phase,
+            row.variant.unwrap_or("-"),
+            human(row.median_ns),
+            human(row.min_ns),
+            human(row.mean_ns),
+            relative,
+        );
+    }
+}
+
+fn human(nanos: u128) -> String {
+    match nanos {
+        0..=999 => format!("{nanos}ns"),
+        1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
@@ -0,0 +1,43 @@
+//! `aoc day1 ...`: calorie inventory tools beyond the puzzle's answers.
+
+use aoc_core::{input::Source, Solution};
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub(crate) enum Command {
+    /// List every elf's total and rank, then the inventory's statistics
+    Report {
+        /// Puzzle input file, or `-` for stdin
+        #[arg(long)]
+        input: Option<Source>,
+    },
+    /// Sum the largest totals, reading the input line by line so it can be
+    /// any size
+    Top {
+        /// How many elves to sum
+        #[arg(short, default_value_t = 3)]
+        n: usize,
+        /// Puzzle input file, or `-` for stdin
+        #[arg(long)]
+        input: Option<Source>,
+    },
+}
+
+pub(crate) fn run(command: Command) -> color_eyre::Result<()> {
+    match command {
+        Command::Report { input } => {
+            let inventory = day1::Day1::parse(&crate::load(1, input)?)?;
+            print!("{}", inventory.report());
+        }
+        Command::Top { n, input } => {
+            let summary = day1::stream::top_n(crate::source(1, input)?.reader()?, n)?;
+            println!("{} elves carrying {} items", summary.elves, summary.items);
+            println!("top {n}: {:?}", summary.top);
+            match summary.top_sum() {
+                Some(sum) => println!("sum: {sum}"),
+                None => println!("sum: more than 64 bits"),
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,186 @@
+//! `aoc day2 ...`: reading the strategy guide in other ways.
+
+use std::path::PathBuf;
+
+use aoc_core::{input::Source, Solution};
+use clap::{Subcommand, ValueEnum};
+use color_eyre::eyre::eyre;
+use day2::{
+    FrequencyCounter, Game, Goal, Interpretation, MatchReport, Player, Random, Sequence,
+    WinStayLoseShift,
+};
+
+#[derive(Clone, Copy, ValueEnum)]
+pub(crate) enum GameName {
+    /// Rock Paper Scissors
+    Rps,
+    /// Rock Paper Scissors Lizard Spock
+    Rpsls,
+}
+
+impl GameName {
+    fn game(self) -> Game {
+        match self {
+            GameName::Rps => Game::rps(),
+            GameName::Rpsls => Game::rpsls(),
+        }
+    }
+}
+
+#[derive(Subcommand)]
+pub(crate) enum Command {
+    /// Total score with the second column read a given way
+    Score {
+        /// `moves`, `outcomes`, or a mapping like `X=rock,Y=win,Z=paper`
+        #[arg(long, default_value = "moves")]
+        mode: String,
+        #[arg(long, value_enum, default_value_t = GameName::Rps)]
+        game: GameName,
+        /// Puzzle input file, or `-` for stdin
+        #[arg(long)]
+        input: Option<Source>,
+    },
+    /// Win/draw/loss counts, streaks and their favourite moves
+    Report {
+        /// `moves`, `outcomes`, or a mapping like `X=rock,Y=win,Z=paper`
+        #[arg(long, default_value = "moves")]
+        mode: String,
+        #[arg(long, value_enum, default_value_t = GameName::Rps)]
+        game: GameName,
+        /// Also write every round's scores and running totals to this file
+        #[arg(long)]
+        csv: Option<PathBuf>,
+        /// Puzzle input file, or `-` for stdin
+        #[arg(long)]
+        input: Option<Source>,
+    },
+    /// Play two strategies against each other
+    Simulate {
+        /// `ours` or `theirs` (a column of the guide, repeated),
+        /// `random[:SEED]`, `frequency` or `wsls` (win-stay/lose-shift)
+        a: String,
+        /// The opponent, as for `a`
+        b: String,
+        #[arg(long, default_value_t = 1000)]
+        rounds: usize,
+        /// How to read the guide's second column for `ours`
+        #[arg(long, default_value = "moves")]
+        mode: String,
+        #[arg(long, value_enum, default_value_t = GameName::Rps)]
+        game: GameName,
+        /// Guide for `ours` and `theirs`, or `-` for stdin
+        #[arg(long)]
+        input: Option<Source>,
+    },
+    /// Try every meaning of the second column's letters, best first
+    Decode {
+        /// `max`, `min`, or a target total score
+        #[arg(long, default_value = "max")]
+        goal: Goal,
+        #[arg(long, value_enum, default_value_t = GameName::Rps)]
+        game: GameName,
+        /// Puzzle input file, or `-` for stdin
+        #[arg(long)]
+        input: Option<Source>,
+    },
+}
+
+pub(crate) fn run(command: Command) -> color_eyre::Result<()> {
+    match command {
+        Command::Score { mode, game, input } => {
+            let game = game.game();
+            let interpretation = Interpretation::parse(&game, &mode)?;
+            let guide = day2::Day2::parse(&crate::load(2, input)?)?;
+            println!("{}", day2::total_score(&game, &guide, &interpretation)?);
+        }
+        Command::Report {
+            mode,
+            game,
+            csv,
+            input,
+        } => {
+            let game = game.game();
+            let interpretation = Interpretation::parse(&game, &mode)?;
+            let guide = day2::Day2::parse(&crate::load(2, input)?)?;
+            let report = MatchReport::new(&game, &day2::rounds(&game, &guide, &interpretation)?);
+            print!("{report}");
+            if let Some(path) = csv {
+                report.write_csv(std::io::BufWriter::new(fs_err::File::create(path)?))?;
+            }
+        }
+        Command::Simulate {
+            a,
+            b,
+            rounds,
+            mode,
+            game,
+            input,
+        } => {
+            let game = game.game();
+            // only sequence players need the guide
+            let mut guide = Vec::new();
+            if [&a, &b]
+                .iter()
+                .any(|p| ["ours", "theirs"].contains(&p.as_str()))
+            {
+                let text = day2::Day2::parse(&crate::load(2, input)?)?;
+                guide = day2::rounds(&game, &text, &Interpretation::parse(&game, &mode)?)?;
+            }
+            let mut a = player(&game, &guide, &a)?;
+            let mut b = player(&game, &guide, &b)?;
+
+            println!("{} vs {}", a.name(), b.name());
+            let played = day2::play_match(a.as_mut(), b.as_mut(), rounds);
+            print!("{}", MatchReport::new(&game, &played));
+        }
+        Command::Decode { goal, game, input } => {
+            let game = game.game();
+            let guide = day2::Day2::parse(&crate::load(2, input)?)?;
+            println!(
+                "{:>4}  {:<36} {:>8} {:>6} {:>6} {:>6}",
+                "rank", "mapping", "score", "wins", "draws", "losses"
+            );
+            for (rank, d) in day2::decode(&game, &guide, goal)?.iter().enumerate() {
+                println!(
+                    "{:>4}  {:<36} {:>8} {:>6} {:>6} {:>6}",
+                    rank + 1,
+                    d.interpretation.describe(&game),
+                    d.score,
+                    d.wins,
+                    d.draws,
+                    d.losses
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn player<'g>(
+    game: &'g Game,
+    guide: &[day2::Round<'g>],
+    spec: &str,
+) -> color_eyre::Result<Box<dyn Player<'g> + 'g>> {
+    let column = |ours: bool| -> color_eyre::Result<_> {
+        let moves: Vec<_> = guide
+            .iter()
+            .map(|r| if ours { r.ours } else { r.theirs })
+            .collect();
+        if moves.is_empty() {
+            return Err(eyre!("the guide has no rounds"));
+        }
+        Ok(Box::new(Sequence::new(moves)))
+    };
+
+    Ok(match spec {
+        "ours" => column(true)?,
+        "theirs" => column(false)?,
+        "frequency" => Box::new(FrequencyCounter::new(game)),
+        "wsls" => Box::new(WinStayLoseShift::new(game)),
+        "random" => Box::new(Random::new(game, 0)),
+        _ => match spec.strip_prefix("random:") {
+            Some(seed) => Box::new(Random::new(game, seed.parse()?)),
+            None => return Err(eyre!("unknown player {spec:?}")),
+        },
+    })
+}
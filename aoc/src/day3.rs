@@ -0,0 +1,37 @@
+//! `aoc day3 ...`: rucksack tools beyond the puzzle's answers.
+
+use aoc_core::{input::Source, Solution};
+use clap::Subcommand;
+use day3::{Analysis, Layout};
+
+#[derive(Subcommand)]
+pub(crate) enum Command {
+    /// Show each rucksack's duplicates and how to move them apart, item
+    /// frequencies and each group's badge
+    Analyze {
+        /// Compartments per rucksack
+        #[arg(long, default_value_t = 2)]
+        compartments: usize,
+        /// Elves per group
+        #[arg(long, default_value_t = 3)]
+        group_size: usize,
+        /// Puzzle input file, or `-` for stdin
+        #[arg(long)]
+        input: Option<Source>,
+    },
+}
+
+pub(crate) fn run(command: Command) -> color_eyre::Result<()> {
+    match command {
+        Command::Analyze {
+            compartments,
+            group_size,
+            input,
+        } => {
+            let layout = Layout::new(compartments, group_size)?;
+            let rucksacks = day3::Day3::parse(&crate::load(3, input)?)?;
+            print!("{}", Analysis::new(&layout, &rucksacks)?);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! `aoc day4 ...`: section assignment tools beyond the puzzle's answers.
+
+use std::path::PathBuf;
+
+use aoc_core::{input::Source, Solution};
+use clap::Subcommand;
+use day4::{Assignments, Drawing, Reversed};
+
+#[derive(Subcommand)]
+pub(crate) enum Command {
+    /// Find overlaps across every elf's assignment, not just within pairs
+    Overlaps {
+        /// List the elves covering this section
+        #[arg(long)]
+        section: Option<u32>,
+        /// List every overlapping pair of elves
+        #[arg(long)]
+        list: bool,
+        /// What to do with ranges whose start is after their end: reject or
+        /// normalise
+        #[arg(long, default_value = "reject")]
+        reversed: Reversed,
+        /// Puzzle input file, or `-` for stdin
+        #[arg(long)]
+        input: Option<Source>,
+    },
+    /// Draw pairs as the puzzle's dotted timelines, marking overlaps
+    Draw {
+        /// Only draw the pair on this line; may be repeated
+        #[arg(long)]
+        line: Vec<usize>,
+        /// Write an SVG of the same pairs to this file instead
+        #[arg(long)]
+        svg: Option<PathBuf>,
+        /// Puzzle input file, or `-` for stdin
+        #[arg(long)]
+        input: Option<Source>,
+    },
+}
+
+pub(crate) fn run(command: Command) -> color_eyre::Result<()> {
+    match command {
+        Command::Overlaps {
+            section,
+            list,
+            reversed,
+            input,
+        } => {
+            let lines = day4::parse_assignments(&crate::load(4, input)?, reversed)?;
+            let assignments = Assignments::from_lines(&lines);
+            let overlapping = assignments.overlapping_pairs();
+
+            println!("{} assignments", assignments.len());
+            println!("{} overlapping pairs of elves", overlapping.len());
+            if let Some((depth, section)) = assignments.max_depth() {
+                println!("deepest coverage: {depth} elves, first at section {section}");
+            }
+            if let Some(section) = section {
+                let covering = assignments.covering(section);
+                println!("section {section} is covered by {} elves", covering.len());
+                for a in covering {
+                    println!("  elf {:>5} (line {:>5}) {:?}", a.elf, a.line, a.sections);
+                }
+            }
+            if list {
+                for (a, b) in overlapping {
+                    println!("{a} {b}");
+                }
+            }
+        }
+        Command::Draw { line, svg, input } => {
+            let pairs = day4::Day4::parse(&crate::load(4, input)?)?;
+            let mut drawing = Drawing::new(&pairs);
+            if !line.is_empty() {
+                drawing = drawing.lines(&line);
+            }
+            match svg {
+                Some(path) => fs_err::write(path, drawing.svg())?,
+                None => print!("{drawing}"),
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! `aoc day5 ...`: crane tools beyond the puzzle's answers.
+
+use std::num::NonZeroUsize;
+
+use aoc_core::{input::Source, Solution};
+use clap::Subcommand;
+use color_eyre::eyre::eyre;
+use day5::{Capacity, Crane, CrateMover9000, CrateMover9001};
+
+#[derive(Subcommand)]
+pub(crate) enum Command {
+    /// Carry out the procedure with any crane and show the piles
+    Run {
+        /// 9000, 9001, or capacity:K for a crane lifting up to K crates at
+        /// once
+        #[arg(long, default_value = "9000")]
+        crane: String,
+        /// Puzzle input file, or `-` for stdin
+        #[arg(long)]
+        input: Option<Source>,
+    },
+}
+
+pub(crate) fn run(command: Command) -> color_eyre::Result<()> {
+    match command {
+        Command::Run { crane: spec, input } => {
+            let procedure = day5::Day5::parse(&crate::load(5, input)?)?;
+            let crane = crane(&spec)?;
+            let piles = procedure.run(crane.as_ref())?;
+            println!("{}", crane.name());
+            print!("{piles:?}");
+            println!("top crates: {}", piles.tops());
+        }
+    }
+    Ok(())
+}
+
+fn crane(spec: &str) -> color_eyre::Result<Box<dyn Crane>> {
+    Ok(match spec {
+        "9000" => Box::new(CrateMover9000),
+        "9001" => Box::new(CrateMover9001),
+        _ => match spec.strip_prefix("capacity:") {
+            Some(k) => match NonZeroUsize::new(k.parse()?) {
+                Some(k) => Box::new(Capacity(k)),
+                None => return Err(eyre!("a crane has to lift at least one crate")),
+            },
+            None => return Err(eyre!("unknown crane {spec:?}")),
+        },
+    })
+}
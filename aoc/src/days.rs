@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+
+use aoc_core::{bench, Part, Solved};
+
+pub(crate) struct Day {
+    pub(crate) number: u8,
+    /// Crate directory, relative to the workspace root.
+    pub(crate) dir: &'static str,
+    pub(crate) solve: fn(&str, Part) -> color_eyre::Result<Solved>,
+    pub(crate) bench: fn(&str, bench::Options) -> color_eyre::Result<Vec<bench::Measurement>>,
+    /// A random puzzle input from a seed and a size.
+    pub(crate) generate: fn(u64, usize) -> String,
+}
+
+impl Day {
+    fn src_dir(&self) -> PathBuf {
+        crate::workspace_root().join(self.dir).join("src")
+    }
+
+    pub(crate) fn default_input(&self) -> PathBuf {
+        self.src_dir().join("input.txt")
+    }
+
+    /// Every `.txt` file next to the day's sources: the puzzle input and
+    /// any samples.
+    pub(crate) fn inputs(&self) -> color_eyre::Result<Vec<PathBuf>> {
+        let mut inputs = Vec::new();
+        for entry in fs_err::read_dir(self.src_dir())? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                inputs.push(path);
+            }
+        }
+        inputs.sort();
+        Ok(inputs)
+    }
+}
+
+pub(crate) fn find(number: u8) -> color_eyre::Result<&'static Day> {
+    DAYS.iter()
+        .find(|day| day.number == number)
+        .ok_or_else(|| color_eyre::eyre::eyre!("day {number} is not solved yet"))
+}
+
+/// The given days, or all of them if none are given.
+pub(crate) fn select(numbers: &[u8]) -> color_eyre::Result<Vec<&'static Day>> {
+    if numbers.is_empty() {
+        return Ok(DAYS.iter().collect());
+    }
+    numbers.iter().map(|&number| find(number)).collect()
+}
+
+macro_rules! day {
+    ($number:literal, $dir:literal, $krate:ident :: $solution:ident) => {
+        Day {
+            number: $number,
+            dir: $dir,
+            solve: aoc_core::solve::<$krate::$solution>,
+            bench: aoc_core::bench::run::<$krate::$solution>,
+            generate: $krate::gen::generate,
+        }
+    };
+}
+
+pub(crate) static DAYS: &[Day] = &[
+    day!(1, "day01", day1::Day1),
+    day!(2, "day02", day2::Day2),
+    day!(3, "day03", day3::Day3),
+    day!(4, "day04", day4::Day4),
+    day!(5, "day05", day5::Day5),
+    day!(6, "day06", day6::Day6),
+    day!(7, "day7", day7::Day7),
+    day!(8, "day8", day8::Day8),
+    day!(9, "day09", day09::Day9),
+];
@@ -0,0 +1,174 @@
+use std::path::{Path, PathBuf};
+
+use aoc_core::{bench::Options, input::Source, Part};
+use clap::{Parser, Subcommand};
+use color_eyre::eyre::WrapErr;
+
+mod answers;
+mod bench;
+mod day1;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod days;
+mod report;
+mod verify;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one part of a day's puzzle
+    Run {
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Puzzle input file, or `-` for stdin. Defaults to the day's
+        /// `src/input.txt`
+        #[arg(long)]
+        input: Option<Source>,
+        #[arg(long, value_enum, default_value_t)]
+        format: report::Format,
+        /// Print each day's step-by-step diagnostics to stderr
+        #[arg(long)]
+        trace: bool,
+    },
+    /// Check every solution against the answers in `answers.toml`
+    Verify {
+        /// Only verify these days
+        days: Vec<u8>,
+        /// Save answers that aren't recorded yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time parsing and every implementation of each part
+    Bench {
+        /// Only benchmark these days
+        days: Vec<u8>,
+        /// Input to use for every day instead of its `src/input.txt`
+        #[arg(long)]
+        input: Option<Source>,
+        /// Timed runs per phase
+        #[arg(long, default_value_t = Options::default().samples)]
+        samples: usize,
+        /// Also write the measurements to this file as JSON
+        #[arg(long)]
+        json: Option<PathBuf>,
+    },
+    /// Print a random puzzle input
+    Gen {
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Roughly how many elves, rounds, lines... depending on the day
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// Write to this file instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Day 1 tools
+    Day1 {
+        #[command(subcommand)]
+        command: day1::Command,
+    },
+    /// Day 2 tools
+    Day2 {
+        #[command(subcommand)]
+        command: day2::Command,
+    },
+    /// Day 3 tools
+    Day3 {
+        #[command(subcommand)]
+        command: day3::Command,
+    },
+    /// Day 4 tools
+    Day4 {
+        #[command(subcommand)]
+        command: day4::Command,
+    },
+    /// Day 5 tools
+    Day5 {
+        #[command(subcommand)]
+        command: day5::Command,
+    },
+}
+
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// The given input, or the day's `src/input.txt`.
+fn source(day: u8, input: Option<Source>) -> color_eyre::Result<Source> {
+    let day = days::find(day)?;
+    Ok(input.unwrap_or_else(|| day.default_input().into()))
+}
+
+fn load(day: u8, input: Option<Source>) -> color_eyre::Result<String> {
+    Ok(source(day, input)?.load()?)
+}
+
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    match Cli::parse().command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            trace,
+        } => {
+            let day = days::find(day)?;
+            let part = Part::try_from(part)?;
+
+            let input = load(day.number, input)?;
+            aoc_core::trace::enable(trace);
+            let solved = (day.solve)(&input, part)
+                .wrap_err_with(|| format!("day {} part {part}", day.number))?;
+            report::print(day.number, part, &solved, format)?;
+        }
+        Command::Verify { days, record } => {
+            if !verify::verify(&days::select(&days)?, record)? {
+                std::process::exit(1);
+            }
+        }
+        Command::Bench {
+            days,
+            input,
+            samples,
+            json,
+        } => {
+            let options = Options {
+                samples,
+                ..Default::default()
+            };
+            bench::bench(&days::select(&days)?, input, options, json.as_deref())?;
+        }
+        Command::Gen {
+            day,
+            seed,
+            size,
+            output,
+        } => {
+            let input = (days::find(day)?.generate)(seed, size);
+            match output {
+                Some(path) => fs_err::write(path, input)?,
+                None => print!("{input}"),
+            }
+        }
+        Command::Day1 { command } => day1::run(command)?,
+        Command::Day2 { command } => day2::run(command)?,
+        Command::Day3 { command } => day3::run(command)?,
+        Command::Day4 { command } => day4::run(command)?,
+        Command::Day5 { command } => day5::run(command)?,
+    }
+
+    Ok(())
+}
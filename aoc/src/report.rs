@@ -0,0 +1,44 @@
+//! How `aoc run` prints its answer.
+
+use aoc_core::{Answer, Part, Solved};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Default, clap::ValueEnum)]
+pub(crate) enum Format {
+    /// Just the answer
+    #[default]
+    Text,
+    /// `{day, part, answer, parse_ms, solve_ms}`
+    Json,
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a Answer,
+    parse_ms: f64,
+    solve_ms: f64,
+}
+
+pub(crate) fn print(
+    day: u8,
+    part: Part,
+    solved: &Solved,
+    format: Format,
+) -> color_eyre::Result<()> {
+    match format {
+        Format::Text => println!("{}", solved.answer),
+        Format::Json => {
+            let report = Report {
+                day,
+                part: part.into(),
+                answer: &solved.answer,
+                parse_ms: solved.parse_time.as_secs_f64() * 1000.0,
+                solve_ms: solved.solve_time.as_secs_f64() * 1000.0,
+            };
+            println!("{}", serde_json::to_string(&report)?);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,107 @@
+//! `aoc verify`: runs every solution against its inputs and checks the
+//! answers against `answers.toml`.
+
+use std::time::{Duration, Instant};
+
+use aoc_core::{input::Source, Answer, Part, Unsolved};
+
+use crate::{answers::Answers, days::Day};
+
+enum Status {
+    Pass,
+    Fail { expected: Answer },
+    Missing,
+    Unsolved,
+    Error(color_eyre::Report),
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "FAIL",
+            Status::Missing => "missing",
+            Status::Unsolved => "unsolved",
+            Status::Error(_) => "ERROR",
+        }
+    }
+}
+
+/// Returns whether every recorded answer still matches. With `record`,
+/// answers that weren't recorded yet are saved as the new reference.
+pub(crate) fn verify(days: &[&Day], record: bool) -> color_eyre::Result<bool> {
+    let mut answers = Answers::load()?;
+    let mut ok = true;
+    let mut recorded = 0;
+
+    for day in days {
+        for path in day.inputs()? {
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            let input = Source::Path(path).load()?;
+
+            for part in [Part::One, Part::Two] {
+                let start = Instant::now();
+                let result = (day.solve)(&input, part).map(|solved| solved.answer);
+                let elapsed = start.elapsed();
+
+                let (status, answer) = match result {
+                    Ok(answer) => match answers.get(day.number, &name, part) {
+                        Some(expected) if *expected == answer => (Status::Pass, Some(answer)),
+                        Some(expected) => (
+                            Status::Fail {
+                                expected: expected.clone(),
+                            },
+                            Some(answer),
+                        ),
+                        None => (Status::Missing, Some(answer)),
+                    },
+                    Err(e) if e.downcast_ref::<Unsolved>().is_some() => (Status::Unsolved, None),
+                    Err(e) => (Status::Error(e), None),
+                };
+
+                print_row(day.number, part, &name, &status, answer.as_ref(), elapsed);
+
+                match (&status, answer) {
+                    (Status::Fail { .. } | Status::Error(_), _) => ok = false,
+                    (Status::Missing, Some(answer)) if record => {
+                        answers.insert(day.number, &name, part, answer);
+                        recorded += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    if recorded > 0 {
+        answers.save()?;
+        println!(
+            "recorded {recorded} new answer(s) in {}",
+            Answers::path().display()
+        );
+    }
+
+    Ok(ok)
+}
+
+fn print_row(
+    day: u8,
+    part: Part,
+    input: &str,
+    status: &Status,
+    answer: Option<&Answer>,
+    elapsed: Duration,
+) {
+    let detail = match (status, answer) {
+        (Status::Fail { expected }, Some(answer)) => format!("{answer} (expected {expected})"),
+        (Status::Error(e), _) => format!("{e:#}"),
+        (_, Some(answer)) => answer.to_string(),
+        (_, None) => String::new(),
+    };
+
+    println!(
+        "day {day:>2} part {part}  {input:<20} {:<8} {:>10.3}ms  {detail}",
+        status.label(),
+        elapsed.as_secs_f64() * 1000.0,
+    );
+}
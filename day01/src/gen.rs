@@ -0,0 +1,17 @@
+//! Random calorie lists.
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// `size` elves carrying one to six items each.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let elves: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            (0..rng.gen_range(1..=6))
+                .map(|_| format!("{}\n", rng.gen_range(1000..=60000)))
+                .collect()
+        })
+        .collect();
+    elves.join("\n")
+}
@@ -0,0 +1,162 @@
+use std::fmt;
+
+/// One elf and the calories of every item they carry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// Position in the input, from 0.
+    pub index: usize,
+    pub items: Vec<u64>,
+}
+
+impl Elf {
+    /// `None` if it doesn't fit in 64 bits.
+    pub fn checked_total(&self) -> Option<u64> {
+        self.items
+            .iter()
+            .try_fold(0_u64, |sum, &item| sum.checked_add(item))
+    }
+
+    /// Panics if the total doesn't fit in 64 bits, which parsing rules out.
+    pub fn total(&self) -> u64 {
+        self.checked_total()
+            .expect("elf totals are checked when parsing")
+    }
+}
+
+/// Every elf, in input order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    pub elves: Vec<Elf>,
+}
+
+impl Inventory {
+    /// Elves by decreasing total; elves with equal totals stay in input
+    /// order.
+    pub fn ranked(&self) -> Vec<&Elf> {
+        let mut elves: Vec<_> = self.elves.iter().collect();
+        elves.sort_by_key(|elf| std::cmp::Reverse(elf.total()));
+        elves
+    }
+
+    /// The `n` elves carrying the most, or all of them if there are fewer.
+    pub fn top(&self, n: usize) -> Vec<&Elf> {
+        let mut ranked = self.ranked();
+        ranked.truncate(n);
+        ranked
+    }
+
+    /// `None` if the sum doesn't fit in 64 bits.
+    pub fn top_sum(&self, n: usize) -> Option<u64> {
+        self.top(n)
+            .iter()
+            .try_fold(0_u64, |sum, elf| sum.checked_add(elf.total()))
+    }
+
+    /// 1 for the elf carrying the most. Elves with equal totals share a
+    /// rank, and the next one down skips as many ranks as were shared.
+    pub fn rank(&self, index: usize) -> Option<usize> {
+        let total = self.elves.get(index)?.total();
+        Some(1 + self.elves.iter().filter(|elf| elf.total() > total).count())
+    }
+
+    fn sorted_totals(&self) -> Vec<u64> {
+        let mut totals: Vec<_> = self.elves.iter().map(Elf::total).collect();
+        totals.sort_unstable();
+        totals
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        // in floating point, as the sum of every total may not fit in 64 bits
+        let sum: f64 = self.elves.iter().map(|elf| elf.total() as f64).sum();
+        (!self.elves.is_empty()).then(|| sum / self.elves.len() as f64)
+    }
+
+    /// The middle total, or the mean of the two middle ones.
+    pub fn median(&self) -> Option<f64> {
+        let totals = self.sorted_totals();
+        let mid = totals.len() / 2;
+        match totals.len() {
+            0 => None,
+            len if len % 2 == 1 => Some(totals[mid] as f64),
+            _ => Some(totals[mid - 1] as f64 + (totals[mid] - totals[mid - 1]) as f64 / 2.0),
+        }
+    }
+
+    /// The smallest total that at least `p` percent of elves don't exceed
+    /// (nearest rank).
+    pub fn percentile(&self, p: f64) -> Option<u64> {
+        let totals = self.sorted_totals();
+        if totals.is_empty() || !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        let rank = (p / 100.0 * totals.len() as f64).ceil() as usize;
+        Some(totals[rank.clamp(1, totals.len()) - 1])
+    }
+
+    /// Totals carried by more than one elf, largest first, with the
+    /// indices of those elves.
+    pub fn ties(&self) -> Vec<(u64, Vec<usize>)> {
+        let mut ties: Vec<(u64, Vec<usize>)> = Vec::new();
+        for elf in self.ranked() {
+            match ties.last_mut() {
+                Some((total, indices)) if *total == elf.total() => indices.push(elf.index),
+                _ => ties.push((elf.total(), vec![elf.index])),
+            }
+        }
+        ties.retain(|(_, indices)| indices.len() > 1);
+        ties
+    }
+
+    /// Every elf's total and rank, followed by the statistics.
+    pub fn report(&self) -> Report<'_> {
+        Report(self)
+    }
+}
+
+pub struct Report<'a>(&'a Inventory);
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inventory = self.0;
+        let totals = inventory.sorted_totals();
+        // same as `Inventory::rank`, without rescanning every elf each time
+        let rank = |total| 1 + totals.len() - totals.partition_point(|&t| t <= total);
+
+        writeln!(
+            f,
+            "{:>6} {:>6} {:>10} {:>6}",
+            "elf", "items", "total", "rank"
+        )?;
+        for elf in &inventory.elves {
+            writeln!(
+                f,
+                "{:>6} {:>6} {:>10} {:>6}",
+                elf.index,
+                elf.items.len(),
+                elf.total(),
+                rank(elf.total())
+            )?;
+        }
+
+        let (Some(mean), Some(median)) = (inventory.mean(), inventory.median()) else {
+            return writeln!(f, "no elves");
+        };
+        writeln!(f)?;
+        writeln!(f, "elves   {}", inventory.elves.len())?;
+        writeln!(f, "mean    {mean:.1}")?;
+        writeln!(f, "median  {median:.1}")?;
+        for p in [90.0, 99.0] {
+            writeln!(f, "p{p:<6} {}", inventory.percentile(p).unwrap())?;
+        }
+        for n in [1, 3] {
+            match inventory.top_sum(n) {
+                Some(sum) => writeln!(f, "top {n}   {sum}")?,
+                None => writeln!(f, "top {n}   more than 64 bits")?,
+            }
+        }
+        for (total, indices) in inventory.ties() {
+            writeln!(f, "tie     {total} carried by elves {indices:?}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use aoc_core::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+pub mod gen;
+mod inventory;
+pub mod stream;
+
+pub use inventory::{Elf, Inventory, Report};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    /// Calories of each item, grouped by the elf carrying them.
+    type Input = Inventory;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        let elves = parse::sections(input)
+            .enumerate()
+            .map(|(index, elf)| {
+                let items: Vec<u64> =
+                    parse::lines(elf, parse::number).map_err(|e| ParseError::new(input, e))?;
+                let mut total = 0_u64;
+                for (line, &item) in elf.lines().zip(&items) {
+                    total = total.checked_add(item).ok_or_else(|| {
+                        ParseError::at(input, line, "an elf total that fits in 64 bits")
+                    })?;
+                }
+                Ok(Elf { index, items })
+            })
+            .collect::<color_eyre::Result<_>>()?;
+        Ok(Inventory { elves })
+    }
+
+    /// Calories carried by the elf carrying the most.
+    fn part1(inventory: &Self::Input) -> color_eyre::Result<Answer> {
+        if inventory.elves.is_empty() {
+            return Err(color_eyre::eyre::eyre!("there are no elves"));
+        }
+        Ok(inventory.top(1)[0].total().into())
+    }
+
+    /// Calories carried by the top three elves.
+    fn part2(inventory: &Self::Input) -> color_eyre::Result<Answer> {
+        if inventory.elves.len() < 3 {
+            return Err(color_eyre::eyre::eyre!("there are fewer than three elves"));
+        }
+        let sum = inventory.top_sum(3);
+        Ok(sum
+            .ok_or_else(|| {
+                color_eyre::eyre::eyre!("the top three totals add up to more than 64 bits")
+            })?
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+    use proptest::prelude::*;
+
+    use crate::Day1;
+
+    const SAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    #[test]
+    fn statistics() {
+        let inventory = Day1::parse(SAMPLE).unwrap();
+        assert_eq!(inventory.top_sum(3), Some(45000));
+        assert_eq!(inventory.rank(3), Some(1));
+        assert_eq!(inventory.rank(1), Some(5));
+        assert_eq!(inventory.mean(), Some(11000.0));
+        assert_eq!(inventory.median(), Some(10000.0));
+        assert_eq!(inventory.percentile(50.0), Some(10000));
+        assert_eq!(inventory.percentile(100.0), Some(24000));
+        assert!(inventory.ties().is_empty());
+    }
+
+    #[test]
+    fn streaming_agrees() {
+        let summary = crate::stream::top_n(SAMPLE.as_bytes(), 3).unwrap();
+        assert_eq!(summary.top, vec![24000, 11000, 10000]);
+        assert_eq!((summary.elves, summary.items), (5, 10));
+
+        let error = crate::stream::top_n("1\n\n2\nx3\n".as_bytes(), 3).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"line 4: "x3" is not a number of calories"#
+        );
+        let error = crate::stream::top_n("99999999999999999999\n".as_bytes(), 3).unwrap_err();
+        assert!(matches!(
+            error,
+            crate::stream::StreamError::Overflow { line: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn totals_fit_in_64_bits() {
+        let error = Day1::parse("1\n\n18446744073709551615\n1\n").unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 4, column 1: expected an elf total that fits in 64 bits"));
+
+        let inventory = Day1::parse("18446744073709551615\n\n1\n\n1\n").unwrap();
+        assert_eq!(inventory.top_sum(1), Some(u64::MAX));
+        assert_eq!(inventory.top_sum(3), None);
+        assert_eq!(inventory.median(), Some(1.0));
+        // the two middle totals would overflow if added up
+        let inventory = Day1::parse("18446744073709551615\n\n18446744073709551615\n").unwrap();
+        assert_eq!(inventory.median(), Some(u64::MAX as f64));
+    }
+
+    #[test]
+    fn ties_share_a_rank() {
+        let inventory = Day1::parse("5\n\n3\n2\n\n9\n\n1\n4\n").unwrap();
+        assert_eq!(inventory.ties(), vec![(5, vec![0, 1, 3])]);
+        assert_eq!(inventory.rank(0), Some(2));
+        assert_eq!(inventory.rank(3), Some(2));
+        assert_eq!(inventory.top(2)[1].index, 0);
+    }
+
+    proptest! {
+        #[test]
+        fn streaming_matches_inventory(
+            elves in prop::collection::vec(prop::collection::vec(0_u64..100_000, 1..6), 0..40),
+            n in 1_usize..6,
+        ) {
+            let input: String = elves
+                .iter()
+                .map(|items| items.iter().map(|i| format!("{i}\n")).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n");
+            let inventory = Day1::parse(&input).unwrap();
+            let summary = crate::stream::top_n(input.as_bytes(), n).unwrap();
+
+            prop_assert_eq!(summary.elves, inventory.elves.len());
+            prop_assert_eq!(summary.items, elves.iter().map(Vec::len).sum::<usize>());
+            prop_assert_eq!(summary.top_sum(), inventory.top_sum(n));
+            let top: Vec<_> = inventory.top(n).iter().map(|elf| elf.total()).collect();
+            prop_assert_eq!(summary.top, top);
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Totals of inputs too big to hold in memory, read one line at a time.
+
+use std::{cmp::Reverse, collections::BinaryHeap, io::BufRead};
+
+#[derive(Debug, thiserror::Error)]
+pub enum StreamError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("line {line}: {text:?} is not a number of calories")]
+    NotANumber { line: usize, text: String },
+    #[error("line {line}: {text:?} doesn't fit in 64 bits")]
+    Overflow { line: usize, text: String },
+    #[error("line {line}: the elf's total no longer fits in 64 bits")]
+    TotalOverflow { line: usize },
+}
+
+/// The `n` largest values seen so far, in O(n) space.
+#[derive(Debug, Clone)]
+pub struct TopN {
+    n: usize,
+    smallest_first: BinaryHeap<Reverse<u64>>,
+}
+
+impl TopN {
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            smallest_first: BinaryHeap::with_capacity(n + 1),
+        }
+    }
+
+    pub fn push(&mut self, value: u64) {
+        self.smallest_first.push(Reverse(value));
+        if self.smallest_first.len() > self.n {
+            self.smallest_first.pop();
+        }
+    }
+
+    /// Largest first.
+    pub fn into_sorted_vec(self) -> Vec<u64> {
+        // ascending order of `Reverse` is descending order of the values
+        self.smallest_first
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(value)| value)
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub elves: usize,
+    pub items: usize,
+    /// The largest totals, largest first.
+    pub top: Vec<u64>,
+}
+
+impl Summary {
+    /// `None` if the sum doesn't fit in 64 bits.
+    pub fn top_sum(&self) -> Option<u64> {
+        self.top
+            .iter()
+            .try_fold(0_u64, |sum, &t| sum.checked_add(t))
+    }
+}
+
+/// Totals every elf's calories as lines come in, keeping only the `n`
+/// largest.
+pub fn top_n(mut reader: impl BufRead, n: usize) -> Result<Summary, StreamError> {
+    let mut top = TopN::new(n);
+    let mut elves = 0;
+    let mut items = 0;
+    let mut current: Option<u64> = None;
+    let mut buf = Vec::new();
+
+    for line in 1.. {
+        buf.clear();
+        if reader.read_until(b'\n', &mut buf)? == 0 {
+            break;
+        }
+
+        let text = String::from_utf8_lossy(&buf);
+        let text = text.trim_end_matches(['\n', '\r']);
+        if text.is_empty() {
+            if let Some(total) = current.take() {
+                top.push(total);
+                elves += 1;
+            }
+            continue;
+        }
+
+        let calories = parse_calories(line, text)?;
+        let total = current
+            .unwrap_or(0)
+            .checked_add(calories)
+            .ok_or(StreamError::TotalOverflow { line })?;
+        current = Some(total);
+        items += 1;
+    }
+
+    if let Some(total) = current {
+        top.push(total);
+        elves += 1;
+    }
+
+    Ok(Summary {
+        elves,
+        items,
+        top: top.into_sorted_vec(),
+    })
+}
+
+fn parse_calories(line: usize, text: &str) -> Result<u64, StreamError> {
+    if !text.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(StreamError::NotANumber {
+            line,
+            text: text.to_owned(),
+        });
+    }
+    text.parse().map_err(|_| StreamError::Overflow {
+        line,
+        text: text.to_owned(),
+    })
+}
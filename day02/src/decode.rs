@@ -0,0 +1,156 @@
+//! Working out what an unknown guide's second column means by trying
+//! every possibility.
+
+use std::str::FromStr;
+
+use color_eyre::eyre::eyre;
+use itertools::Itertools;
+
+use crate::{Game, Interpretation, Meaning, Outcome};
+
+/// What we'd like the total score to be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    Max,
+    Min,
+    Target(usize),
+}
+
+impl FromStr for Goal {
+    type Err = color_eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "max" => Ok(Goal::Max),
+            "min" => Ok(Goal::Min),
+            _ => s
+                .parse()
+                .map(Goal::Target)
+                .map_err(|_| eyre!("expected max, min or a target score, got {s:?}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decoding {
+    pub interpretation: Interpretation,
+    pub score: usize,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+/// Scores every bijection of the second column's letters onto moves of
+/// `game`, then onto outcomes, best first according to `goal`. Ties keep
+/// that order. Mappings asking for an outcome that no move can get against
+/// some round are left out.
+pub fn decode(
+    game: &Game,
+    guide: &[(char, char)],
+    goal: Goal,
+) -> color_eyre::Result<Vec<Decoding>> {
+    for &(theirs, _) in guide {
+        game.parse_move(theirs)?;
+    }
+    let letters: Vec<char> = guide
+        .iter()
+        .map(|&(_, ours)| ours)
+        .unique()
+        .sorted()
+        .collect();
+
+    let moves: Vec<Meaning> = game.moves().map(|m| Meaning::Move(m.index())).collect();
+    let outcomes: Vec<Meaning> = Outcome::ALL.into_iter().map(Meaning::Outcome).collect();
+
+    let mut decodings = Vec::new();
+    for meanings in [moves, outcomes] {
+        // fewer meanings than letters can't be a bijection
+        for permutation in meanings.into_iter().permutations(letters.len()) {
+            let mapping = letters.iter().copied().zip(permutation).collect();
+            // with their moves checked, only an impossible outcome fails
+            if let Ok(decoding) = score(game, guide, Interpretation::Mapping(mapping)) {
+                decodings.push(decoding);
+            }
+        }
+    }
+
+    match goal {
+        Goal::Max => decodings.sort_by_key(|d| std::cmp::Reverse(d.score)),
+        Goal::Min => decodings.sort_by_key(|d| d.score),
+        Goal::Target(target) => decodings.sort_by_key(|d| d.score.abs_diff(target)),
+    }
+    Ok(decodings)
+}
+
+fn score(
+    game: &Game,
+    guide: &[(char, char)],
+    interpretation: Interpretation,
+) -> color_eyre::Result<Decoding> {
+    let mut decoding = Decoding {
+        interpretation,
+        score: 0,
+        wins: 0,
+        draws: 0,
+        losses: 0,
+    };
+    for &columns in guide {
+        let round = decoding.interpretation.round(game, columns)?;
+        decoding.score += round.our_score();
+        *match round.outcome() {
+            Outcome::Win => &mut decoding.wins,
+            Outcome::Draw => &mut decoding.draws,
+            Outcome::Loss => &mut decoding.losses,
+        } += 1;
+    }
+    Ok(decoding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, Goal};
+    use crate::{Game, Interpretation, MoveDef};
+
+    #[test]
+    fn sample_guide() {
+        let game = Game::rps();
+        let guide = [('A', 'Y'), ('B', 'X'), ('C', 'Z')];
+
+        let decodings = decode(&game, &guide, Goal::Max).unwrap();
+        assert_eq!(decodings.len(), 12);
+        assert_eq!(decodings[0].score, 24);
+        assert_eq!(decodings[0].wins, 3);
+
+        let part1 = Interpretation::parse(&game, "X=rock,Y=paper,Z=scissors").unwrap();
+        let target = decode(&game, &guide, Goal::Target(15)).unwrap();
+        assert_eq!(target[0].score, 15);
+        assert!(target
+            .iter()
+            .any(|d| d.score == 15 && d.interpretation == part1));
+
+        let min = decode(&game, &guide, Goal::Min).unwrap();
+        assert_eq!((min[0].score, min[0].losses), (6, 3));
+    }
+
+    #[test]
+    fn impossible_outcomes() {
+        // paper beats rock, and nothing beats paper
+        let game = Game::new(
+            vec![
+                MoveDef::new("Rock", &['A', 'X'], 1),
+                MoveDef::new("Paper", &['B', 'Y'], 2),
+            ],
+            [(1, 0)],
+        )
+        .unwrap();
+        let guide = [('A', 'X'), ('B', 'Y')];
+
+        // both move mappings, and the outcome mappings that never ask for a
+        // loss against rock or a win against paper
+        let decodings = decode(&game, &guide, Goal::Max).unwrap();
+        assert_eq!(decodings.len(), 5);
+        assert_eq!(decodings[0].score, 13);
+
+        assert!(decode(&game, &[('C', 'X')], Goal::Max).is_err());
+    }
+}
@@ -0,0 +1,294 @@
+//! Games like Rock Paper Scissors, defined by data: which moves there are,
+//! the letters standing for them, what they're worth and what beats what.
+
+use std::fmt;
+
+use color_eyre::eyre::eyre;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveDef {
+    pub name: String,
+    /// Letters standing for this move in a strategy guide.
+    pub letters: Vec<char>,
+    pub points: usize,
+}
+
+impl MoveDef {
+    pub fn new(name: &str, letters: &[char], points: usize) -> Self {
+        Self {
+            name: name.to_owned(),
+            letters: letters.to_vec(),
+            points,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    moves: Vec<MoveDef>,
+    /// `beats[a][b]`: move `a` beats move `b`. Neither beating the other is
+    /// a draw.
+    beats: Vec<Vec<bool>>,
+}
+
+impl Game {
+    /// Any tournament: `beats` lists `(winner, loser)` pairs of indices
+    /// into `moves`.
+    pub fn new(
+        moves: Vec<MoveDef>,
+        beats: impl IntoIterator<Item = (usize, usize)>,
+    ) -> color_eyre::Result<Self> {
+        let n = moves.len();
+        let mut table = vec![vec![false; n]; n];
+        for (winner, loser) in beats {
+            if winner >= n || loser >= n {
+                return Err(eyre!("there is no move {}", winner.max(loser)));
+            }
+            if winner == loser {
+                return Err(eyre!("{} can't beat itself", moves[winner].name));
+            }
+            if table[loser][winner] {
+                let (w, l) = (&moves[winner].name, &moves[loser].name);
+                return Err(eyre!("{w} and {l} can't both beat each other"));
+            }
+            table[winner][loser] = true;
+        }
+
+        let mut letters: Vec<char> = moves.iter().flat_map(|m| m.letters.clone()).collect();
+        letters.sort_unstable();
+        if let Some(pair) = letters.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(eyre!("{:?} stands for more than one move", pair[0]));
+        }
+
+        Ok(Self {
+            moves,
+            beats: table,
+        })
+    }
+
+    /// An odd number of moves in a circle, each beating the half of the
+    /// others just before it.
+    pub fn cyclic(moves: Vec<MoveDef>) -> color_eyre::Result<Self> {
+        let n = moves.len();
+        if n.is_multiple_of(2) {
+            return Err(eyre!("a fair cyclic game needs an odd number of moves"));
+        }
+        let beats: Vec<_> = (0..n)
+            .flat_map(|winner| (1..=n / 2).map(move |d| (winner, (winner + n - d) % n)))
+            .collect();
+        Self::new(moves, beats)
+    }
+
+    /// The puzzle's game.
+    pub fn rps() -> Self {
+        Self::cyclic(vec![
+            MoveDef::new("Rock", &['A', 'X'], 1),
+            MoveDef::new("Paper", &['B', 'Y'], 2),
+            MoveDef::new("Scissors", &['C', 'Z'], 3),
+        ])
+        .expect("rock paper scissors is well-formed")
+    }
+
+    /// Rock Paper Scissors Lizard Spock, with `D`/`V` for Lizard and
+    /// `E`/`W` for Spock.
+    pub fn rpsls() -> Self {
+        // in this order, each move beats the two before it
+        Self::cyclic(vec![
+            MoveDef::new("Rock", &['A', 'X'], 1),
+            MoveDef::new("Spock", &['E', 'W'], 5),
+            MoveDef::new("Paper", &['B', 'Y'], 2),
+            MoveDef::new("Lizard", &['D', 'V'], 4),
+            MoveDef::new("Scissors", &['C', 'Z'], 3),
+        ])
+        .expect("rock paper scissors lizard spock is well-formed")
+    }
+
+    pub fn moves(&self) -> impl Iterator<Item = Move<'_>> {
+        (0..self.moves.len()).map(|index| Move { game: self, index })
+    }
+
+    pub fn by_name(&self, name: &str) -> Option<Move<'_>> {
+        self.moves().find(|m| m.name().eq_ignore_ascii_case(name))
+    }
+
+    /// The move `letter` stands for.
+    pub fn parse_move(&self, letter: char) -> color_eyre::Result<Move<'_>> {
+        self.moves()
+            .find(|m| m.def().letters.contains(&letter))
+            .ok_or_else(|| eyre!("Not a valid move: {letter:?}"))
+    }
+}
+
+/// A move of a particular game.
+#[derive(Clone, Copy)]
+pub struct Move<'g> {
+    game: &'g Game,
+    index: usize,
+}
+
+impl<'g> Move<'g> {
+    fn def(self) -> &'g MoveDef {
+        &self.game.moves[self.index]
+    }
+
+    /// Position in the game's list of moves.
+    pub fn index(self) -> usize {
+        self.index
+    }
+
+    pub fn name(self) -> &'g str {
+        &self.def().name
+    }
+
+    pub fn points(self) -> usize {
+        self.def().points
+    }
+
+    pub fn beats(self, other: Self) -> bool {
+        self.game.beats[self.index][other.index]
+    }
+
+    pub fn outcome(self, theirs: Self) -> Outcome {
+        if self.beats(theirs) {
+            Outcome::Win
+        } else if theirs.beats(self) {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// The first move that beats this one, if any does.
+    pub fn winning_move(self) -> Option<Self> {
+        self.game.moves().find(|m| m.beats(self))
+    }
+
+    /// The first move this one beats, if any.
+    pub fn losing_move(self) -> Option<Self> {
+        self.game.moves().find(|&m| self.beats(m))
+    }
+
+    pub fn drawing_move(self) -> Self {
+        self
+    }
+}
+
+impl PartialEq for Move<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.game, other.game) && self.index == other.index
+    }
+}
+
+impl Eq for Move<'_> {}
+
+impl fmt::Debug for Move<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+    pub fn points(self) -> usize {
+        match self {
+            Outcome::Win => 6,
+            Outcome::Draw => 3,
+            Outcome::Loss => 0,
+        }
+    }
+
+    /// The same round seen from the other side.
+    pub fn reversed(self) -> Self {
+        match self {
+            Outcome::Win => Outcome::Loss,
+            Outcome::Draw => Outcome::Draw,
+            Outcome::Loss => Outcome::Win,
+        }
+    }
+
+    /// The move to play against `theirs` to get this outcome, if there is
+    /// one.
+    pub fn matching_move(self, theirs: Move<'_>) -> Option<Move<'_>> {
+        match self {
+            Outcome::Win => theirs.winning_move(),
+            Outcome::Draw => Some(theirs.drawing_move()),
+            Outcome::Loss => theirs.losing_move(),
+        }
+    }
+}
+
+impl TryFrom<char> for Outcome {
+    type Error = color_eyre::Report;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'X' => Ok(Outcome::Loss),
+            'Y' => Ok(Outcome::Draw),
+            'Z' => Ok(Outcome::Win),
+            _ => Err(eyre!("Not a valid outcome: {c:?}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Game, MoveDef, Outcome};
+
+    #[test]
+    fn rpsls_rules() {
+        let game = Game::rpsls();
+        let m = |letter| game.parse_move(letter).unwrap();
+        let (rock, paper, scissors, lizard, spock) = (m('A'), m('B'), m('C'), m('D'), m('E'));
+
+        for (winner, loser) in [
+            (scissors, paper),
+            (paper, rock),
+            (rock, lizard),
+            (lizard, spock),
+            (spock, scissors),
+            (scissors, lizard),
+            (lizard, paper),
+            (paper, spock),
+            (spock, rock),
+            (rock, scissors),
+        ] {
+            assert_eq!(
+                winner.outcome(loser),
+                Outcome::Win,
+                "{winner:?} vs {loser:?}"
+            );
+            assert_eq!(
+                loser.outcome(winner),
+                Outcome::Loss,
+                "{loser:?} vs {winner:?}"
+            );
+        }
+        assert_eq!(
+            Outcome::Win.matching_move(spock).map(|m| m.name()),
+            Some("Paper")
+        );
+    }
+
+    #[test]
+    fn custom_games() {
+        // a single move that only draws
+        let game = Game::new(vec![MoveDef::new("Stone", &['S'], 7)], []).unwrap();
+        let stone = game.parse_move('S').unwrap();
+        assert_eq!(stone.outcome(stone), Outcome::Draw);
+        assert_eq!(Outcome::Win.matching_move(stone), None);
+
+        let two = || vec![MoveDef::new("A", &['A'], 1), MoveDef::new("B", &['B'], 2)];
+        assert!(Game::new(two(), [(0, 1), (1, 0)]).is_err());
+        assert!(Game::new(two(), [(0, 0)]).is_err());
+        assert!(Game::cyclic(two()).is_err());
+        assert!(Game::new(vec![MoveDef::new("A", &['A'], 1); 2], []).is_err());
+    }
+}
@@ -0,0 +1,16 @@
+//! Random strategy guides.
+
+use rand::{seq::SliceRandom, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// `size` rounds.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    (0..size.max(1))
+        .map(|_| {
+            let theirs = ['A', 'B', 'C'].choose(&mut rng).unwrap();
+            let ours = ['X', 'Y', 'Z'].choose(&mut rng).unwrap();
+            format!("{theirs} {ours}\n")
+        })
+        .collect()
+}
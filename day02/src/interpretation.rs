@@ -0,0 +1,150 @@
+//! What the strategy guide's second column means.
+
+use std::str::FromStr;
+
+use color_eyre::eyre::eyre;
+
+use crate::{Game, Outcome, Round};
+
+/// What a letter in the second column stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Meaning {
+    /// Index into the game's moves.
+    Move(usize),
+    Outcome(Outcome),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Interpretation {
+    /// The move we play, by the game's own letters (part 1).
+    Moves,
+    /// `X`, `Y`, `Z` for the loss, draw or win we're after (part 2).
+    Outcomes,
+    /// Whatever each letter is mapped to.
+    Mapping(Vec<(char, Meaning)>),
+}
+
+impl Interpretation {
+    /// `moves`, `outcomes`, or a mapping like `X=rock,Y=win,Z=paper` where
+    /// each letter stands for a move of `game` or an outcome.
+    pub fn parse(game: &Game, s: &str) -> color_eyre::Result<Self> {
+        match s {
+            "moves" => return Ok(Self::Moves),
+            "outcomes" => return Ok(Self::Outcomes),
+            _ => {}
+        }
+
+        let mut mapping = Vec::new();
+        for entry in s.split(',') {
+            let (letter, name) = entry
+                .split_once('=')
+                .ok_or_else(|| eyre!("expected LETTER=MEANING, got {entry:?}"))?;
+            let mut chars = letter.trim().chars();
+            let (Some(letter), None) = (chars.next(), chars.next()) else {
+                return Err(eyre!("{letter:?} isn't a single letter"));
+            };
+            if mapping.iter().any(|&(l, _)| l == letter) {
+                return Err(eyre!("{letter:?} is mapped twice"));
+            }
+
+            let name = name.trim();
+            let meaning = match game.by_name(name) {
+                Some(m) => Meaning::Move(m.index()),
+                None => Meaning::Outcome(name.parse().map_err(|_| {
+                    eyre!("{name:?} is neither a move of this game nor an outcome")
+                })?),
+            };
+            mapping.push((letter, meaning));
+        }
+        Ok(Self::Mapping(mapping))
+    }
+
+    pub fn meaning(&self, game: &Game, letter: char) -> color_eyre::Result<Meaning> {
+        match self {
+            Self::Moves => Ok(Meaning::Move(game.parse_move(letter)?.index())),
+            Self::Outcomes => Ok(Meaning::Outcome(letter.try_into()?)),
+            Self::Mapping(mapping) => mapping
+                .iter()
+                .find(|&&(l, _)| l == letter)
+                .map(|&(_, meaning)| meaning)
+                .ok_or_else(|| eyre!("{letter:?} isn't mapped to anything")),
+        }
+    }
+
+    /// The interpretation as [`Interpretation::parse`] reads it, with
+    /// `game`'s move names.
+    pub fn describe(&self, game: &Game) -> String {
+        match self {
+            Self::Moves => "moves".to_owned(),
+            Self::Outcomes => "outcomes".to_owned(),
+            Self::Mapping(mapping) => mapping
+                .iter()
+                .map(|&(letter, meaning)| match meaning {
+                    Meaning::Move(index) => {
+                        format!("{letter}={}", game.moves().nth(index).unwrap().name())
+                    }
+                    Meaning::Outcome(outcome) => format!("{letter}={outcome:?}"),
+                })
+                .collect::<Vec<_>>()
+                .join(","),
+        }
+    }
+
+    /// Reads one line of the guide. The first column is always their move.
+    pub fn round<'g>(
+        &self,
+        game: &'g Game,
+        (theirs, ours): (char, char),
+    ) -> color_eyre::Result<Round<'g>> {
+        let theirs = game.parse_move(theirs)?;
+        let ours = match self.meaning(game, ours)? {
+            Meaning::Move(index) => game.moves().nth(index).unwrap(),
+            Meaning::Outcome(outcome) => outcome
+                .matching_move(theirs)
+                .ok_or_else(|| eyre!("no move gets a {outcome:?} against {theirs:?}"))?,
+        };
+        Ok(Round { theirs, ours })
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = color_eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "loss" | "lose" => Ok(Outcome::Loss),
+            "draw" => Ok(Outcome::Draw),
+            "win" => Ok(Outcome::Win),
+            _ => Err(eyre!("Not a valid outcome: {s:?}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Interpretation, Meaning};
+    use crate::{Game, Outcome};
+
+    #[test]
+    fn mappings() {
+        let game = Game::rps();
+        let mapping = Interpretation::parse(&game, "X=rock, Y=Win,Z=paper").unwrap();
+        assert_eq!(
+            mapping,
+            Interpretation::Mapping(vec![
+                ('X', Meaning::Move(0)),
+                ('Y', Meaning::Outcome(Outcome::Win)),
+                ('Z', Meaning::Move(1)),
+            ])
+        );
+
+        let round = mapping.round(&game, ('C', 'Y')).unwrap();
+        assert_eq!(round.ours.name(), "Rock");
+        assert!(mapping.round(&game, ('C', 'W')).is_err());
+
+        assert!(Interpretation::parse(&game, "X=spock").is_err());
+        assert!(Interpretation::parse(&game, "X=rock,X=paper").is_err());
+        assert!(Interpretation::parse(&Game::rpsls(), "X=spock").is_ok());
+        assert_eq!(mapping.describe(&game), "X=Rock,Y=Win,Z=Paper");
+    }
+}
@@ -0,0 +1,181 @@
+// A -> Rock, B -> Paper, C -> Scissors
+// X -> Rock, Y -> Paper, Z -> Scissors (part 1)
+// X -> Loss, Y -> Draw, Z -> Win (part 2)
+
+// Rock = 1 pt, Paper = 2 pt, Scissors = 3 pt
+// Loss = 0, draw = 3, win = 6
+
+use aoc_core::{parse::ParseError, Answer, Solution};
+
+mod decode;
+mod game;
+pub mod gen;
+mod interpretation;
+mod player;
+mod report;
+
+pub use decode::{decode, Decoding, Goal};
+pub use game::{Game, Move, MoveDef, Outcome};
+pub use interpretation::{Interpretation, Meaning};
+pub use player::{play_match, FrequencyCounter, Player, Random, Sequence, WinStayLoseShift};
+pub use report::{MatchReport, RoundRow, Tally};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round<'g> {
+    pub theirs: Move<'g>,
+    pub ours: Move<'g>,
+}
+
+impl Round<'_> {
+    pub fn outcome(self) -> Outcome {
+        self.ours.outcome(self.theirs)
+    }
+
+    pub fn our_score(self) -> usize {
+        self.ours.points() + self.outcome().points()
+    }
+
+    pub fn their_score(self) -> usize {
+        self.theirs.points() + self.outcome().reversed().points()
+    }
+}
+
+/// Splits a `<theirs>SP<ours>` line of `input` into its two columns.
+fn columns<'a>(input: &'a str, line: &'a str) -> Result<(char, char), ParseError> {
+    let mut chars = line.chars();
+    let theirs = chars
+        .next()
+        .ok_or_else(|| ParseError::at(input, line, "<theirs>"))?;
+    let rest = chars.as_str();
+    let rest = rest
+        .strip_prefix(' ')
+        .ok_or_else(|| ParseError::at(input, rest, "\" \""))?;
+    let mut chars = rest.chars();
+    let ours = chars
+        .next()
+        .ok_or_else(|| ParseError::at(input, rest, "<ours>"))?;
+    let rest = chars.as_str();
+    if !rest.is_empty() {
+        return Err(ParseError::at(input, rest, "end of line"));
+    }
+
+    Ok((theirs, ours))
+}
+
+/// Every round of `guide`, read as `interpretation` says.
+pub fn rounds<'g>(
+    game: &'g Game,
+    guide: &[(char, char)],
+    interpretation: &Interpretation,
+) -> color_eyre::Result<Vec<Round<'g>>> {
+    guide
+        .iter()
+        .map(|&columns| interpretation.round(game, columns))
+        .collect()
+}
+
+/// Our total score following `guide`, read as `interpretation` says.
+pub fn total_score(
+    game: &Game,
+    guide: &[(char, char)],
+    interpretation: &Interpretation,
+) -> color_eyre::Result<usize> {
+    Ok(rounds(game, guide, interpretation)?
+        .into_iter()
+        .inspect(|round| {
+            aoc_core::trace!("{round:?}: {:?}, {}", round.outcome(), round.our_score())
+        })
+        .map(Round::our_score)
+        .sum())
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    /// The strategy guide's two columns; what the second one means depends
+    /// on the part.
+    type Input = Vec<(char, char)>;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(input
+            .lines()
+            .map(|line| columns(input, line))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(guide: &Self::Input) -> color_eyre::Result<Answer> {
+        Ok(total_score(&Game::rps(), guide, &Interpretation::Moves)?.into())
+    }
+
+    fn part2(guide: &Self::Input) -> color_eyre::Result<Answer> {
+        Ok(total_score(&Game::rps(), guide, &Interpretation::Outcomes)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use aoc_core::Solution;
+
+    use crate::{rounds, total_score, Day2, Game, Interpretation, Outcome};
+
+    fn guide() -> impl Strategy<Value = Vec<(char, char)>> {
+        prop::collection::vec(
+            (prop::char::range('A', 'C'), prop::char::range('X', 'Z')),
+            0..50,
+        )
+    }
+
+    /// 0 for rock, 1 for paper, 2 for scissors.
+    fn shape(c: char, first: char) -> usize {
+        c as usize - first as usize
+    }
+
+    #[test]
+    fn positioned_errors() {
+        let error = Day2::parse("A Y\nA  Y\n").unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "line 2, column 4: expected end of line\n  |\n2 | A  Y\n  |    ^"
+        );
+        let error = Day2::parse("A Y\nBZ\n").unwrap_err().to_string();
+        assert!(
+            error.starts_with("line 2, column 2: expected \" \""),
+            "{error}"
+        );
+    }
+
+    proptest! {
+        // the usual modular arithmetic, as a check on the data-driven game
+        #[test]
+        fn scores_match_arithmetic(guide in guide()) {
+            let game = Game::rps();
+            let moves: usize = guide
+                .iter()
+                .map(|&(a, x)| {
+                    let (theirs, ours) = (shape(a, 'A'), shape(x, 'X'));
+                    1 + ours + 3 * ((ours + 4 - theirs) % 3)
+                })
+                .sum();
+            let outcomes: usize = guide
+                .iter()
+                .map(|&(a, x)| {
+                    let (theirs, outcome) = (shape(a, 'A'), shape(x, 'X'));
+                    1 + (theirs + outcome + 2) % 3 + 3 * outcome
+                })
+                .sum();
+            prop_assert_eq!(total_score(&game, &guide, &Interpretation::Moves).unwrap(), moves);
+            prop_assert_eq!(total_score(&game, &guide, &Interpretation::Outcomes).unwrap(), outcomes);
+        }
+
+        #[test]
+        fn outcomes_are_played_as_asked(guide in guide()) {
+            let game = Game::rpsls();
+            for (round, &(_, x)) in rounds(&game, &guide, &Interpretation::Outcomes).unwrap().iter().zip(&guide) {
+                prop_assert_eq!(round.outcome(), Outcome::try_from(x).unwrap());
+                prop_assert_eq!(round.outcome().points() + round.outcome().reversed().points(), 6);
+            }
+        }
+    }
+}
@@ -0,0 +1,174 @@
+//! Players choosing their own moves, to pit strategies against each other.
+
+use rand::{seq::IteratorRandom, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::{Game, Move, Outcome, Round};
+
+pub trait Player<'g> {
+    fn name(&self) -> String;
+
+    /// The next move, having played `history` so far. Rounds are seen from
+    /// this player's side: `ours` is what it played.
+    fn play(&mut self, history: &[Round<'g>]) -> Move<'g>;
+}
+
+/// Plays the same moves over and over, like a strategy guide does.
+pub struct Sequence<'g> {
+    moves: Vec<Move<'g>>,
+}
+
+impl<'g> Sequence<'g> {
+    /// # Panics
+    ///
+    /// If `moves` is empty.
+    pub fn new(moves: Vec<Move<'g>>) -> Self {
+        assert!(!moves.is_empty(), "a sequence needs at least one move");
+        Self { moves }
+    }
+}
+
+impl<'g> Player<'g> for Sequence<'g> {
+    fn name(&self) -> String {
+        format!("sequence of {}", self.moves.len())
+    }
+
+    fn play(&mut self, history: &[Round<'g>]) -> Move<'g> {
+        self.moves[history.len() % self.moves.len()]
+    }
+}
+
+pub struct Random<'g> {
+    game: &'g Game,
+    seed: u64,
+    rng: ChaCha8Rng,
+}
+
+impl<'g> Random<'g> {
+    pub fn new(game: &'g Game, seed: u64) -> Self {
+        Self {
+            game,
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl<'g> Player<'g> for Random<'g> {
+    fn name(&self) -> String {
+        format!("random (seed {})", self.seed)
+    }
+
+    fn play(&mut self, _history: &[Round<'g>]) -> Move<'g> {
+        self.game.moves().choose(&mut self.rng).unwrap()
+    }
+}
+
+/// Beats whatever the opponent has played most so far.
+pub struct FrequencyCounter<'g> {
+    game: &'g Game,
+}
+
+impl<'g> FrequencyCounter<'g> {
+    pub fn new(game: &'g Game) -> Self {
+        Self { game }
+    }
+}
+
+impl<'g> Player<'g> for FrequencyCounter<'g> {
+    fn name(&self) -> String {
+        "frequency counter".to_owned()
+    }
+
+    fn play(&mut self, history: &[Round<'g>]) -> Move<'g> {
+        let mut counts = vec![0; self.game.moves().count()];
+        for round in history {
+            counts[round.theirs.index()] += 1;
+        }
+        // the first of the most played moves
+        let favourite = self
+            .game
+            .moves()
+            .min_by_key(|m| std::cmp::Reverse(counts[m.index()]))
+            .unwrap();
+        favourite.winning_move().unwrap_or(favourite)
+    }
+}
+
+/// Keeps a winning move; otherwise switches to what would have beaten
+/// the opponent's last move.
+pub struct WinStayLoseShift<'g> {
+    game: &'g Game,
+}
+
+impl<'g> WinStayLoseShift<'g> {
+    pub fn new(game: &'g Game) -> Self {
+        Self { game }
+    }
+}
+
+impl<'g> Player<'g> for WinStayLoseShift<'g> {
+    fn name(&self) -> String {
+        "win-stay/lose-shift".to_owned()
+    }
+
+    fn play(&mut self, history: &[Round<'g>]) -> Move<'g> {
+        match history.last() {
+            None => self.game.moves().next().unwrap(),
+            Some(last) if last.outcome() == Outcome::Win => last.ours,
+            Some(last) => last.theirs.winning_move().unwrap_or(last.ours),
+        }
+    }
+}
+
+/// Plays `rounds` rounds between `a` and `b`, returned from `a`'s side.
+pub fn play_match<'g>(
+    a: &mut dyn Player<'g>,
+    b: &mut dyn Player<'g>,
+    rounds: usize,
+) -> Vec<Round<'g>> {
+    let mut history_a = Vec::with_capacity(rounds);
+    let mut history_b = Vec::with_capacity(rounds);
+    for _ in 0..rounds {
+        let (ours, theirs) = (a.play(&history_a), b.play(&history_b));
+        history_a.push(Round { theirs, ours });
+        history_b.push(Round {
+            theirs: ours,
+            ours: theirs,
+        });
+    }
+    history_a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{play_match, FrequencyCounter, Random, Sequence, WinStayLoseShift};
+    use crate::{Game, Outcome};
+
+    #[test]
+    fn strategies() {
+        let game = Game::rps();
+        let rock = game.by_name("rock").unwrap();
+
+        // always answering rock gets beaten by counting
+        let rounds = play_match(
+            &mut Sequence::new(vec![rock]),
+            &mut FrequencyCounter::new(&game),
+            10,
+        );
+        assert!(rounds[1..].iter().all(|r| r.outcome() == Outcome::Loss));
+
+        // after drawing with rock, shifting to paper wins from then on
+        let rounds = play_match(
+            &mut WinStayLoseShift::new(&game),
+            &mut Sequence::new(vec![rock]),
+            5,
+        );
+        assert_eq!(rounds[0].outcome(), Outcome::Draw);
+        assert!(rounds[1..].iter().all(|r| r.outcome() == Outcome::Win));
+
+        let a = play_match(&mut Random::new(&game, 7), &mut Random::new(&game, 8), 50);
+        let b = play_match(&mut Random::new(&game, 7), &mut Random::new(&game, 8), 50);
+        assert_eq!(a, b);
+    }
+}
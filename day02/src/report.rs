@@ -0,0 +1,191 @@
+//! Round-by-round analysis of a whole match.
+
+use std::{fmt, io};
+
+use crate::{Game, Outcome, Round};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+impl Tally {
+    fn count(&mut self, outcome: Outcome) {
+        *match outcome {
+            Outcome::Win => &mut self.wins,
+            Outcome::Draw => &mut self.draws,
+            Outcome::Loss => &mut self.losses,
+        } += 1;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundRow {
+    pub theirs: String,
+    pub ours: String,
+    pub outcome: Outcome,
+    pub our_score: usize,
+    pub their_score: usize,
+    /// Running totals, including this round.
+    pub our_total: usize,
+    pub their_total: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchReport {
+    pub rounds: Vec<RoundRow>,
+    pub ours: Tally,
+    pub theirs: Tally,
+    /// How often they played each of the game's moves.
+    pub their_moves: Vec<(String, usize)>,
+    pub longest_win_streak: usize,
+    pub longest_loss_streak: usize,
+}
+
+impl MatchReport {
+    pub fn new(game: &Game, rounds: &[Round<'_>]) -> Self {
+        let mut report = Self {
+            rounds: Vec::with_capacity(rounds.len()),
+            ours: Tally::default(),
+            theirs: Tally::default(),
+            their_moves: game.moves().map(|m| (m.name().to_owned(), 0)).collect(),
+            longest_win_streak: 0,
+            longest_loss_streak: 0,
+        };
+
+        let (mut our_total, mut their_total) = (0, 0);
+        let mut streak: Option<(Outcome, usize)> = None;
+        for &round in rounds {
+            let outcome = round.outcome();
+            our_total += round.our_score();
+            their_total += round.their_score();
+            report.ours.count(outcome);
+            report.theirs.count(outcome.reversed());
+            report.their_moves[round.theirs.index()].1 += 1;
+
+            let length = match streak {
+                Some((o, length)) if o == outcome => length + 1,
+                _ => 1,
+            };
+            streak = Some((outcome, length));
+            match outcome {
+                Outcome::Win => report.longest_win_streak = report.longest_win_streak.max(length),
+                Outcome::Loss => {
+                    report.longest_loss_streak = report.longest_loss_streak.max(length)
+                }
+                Outcome::Draw => {}
+            }
+
+            report.rounds.push(RoundRow {
+                theirs: round.theirs.name().to_owned(),
+                ours: round.ours.name().to_owned(),
+                outcome,
+                our_score: round.our_score(),
+                their_score: round.their_score(),
+                our_total,
+                their_total,
+            });
+        }
+
+        report
+    }
+
+    /// One line per round, with the running totals: the score curve.
+    pub fn write_csv(&self, mut w: impl io::Write) -> io::Result<()> {
+        writeln!(
+            w,
+            "round,theirs,ours,outcome,our_score,their_score,our_total,their_total"
+        )?;
+        for (i, r) in self.rounds.iter().enumerate() {
+            writeln!(
+                w,
+                "{},{},{},{:?},{},{},{},{}",
+                i + 1,
+                r.theirs,
+                r.ours,
+                r.outcome,
+                r.our_score,
+                r.their_score,
+                r.our_total,
+                r.their_total
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for MatchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (ours, theirs) = self
+            .rounds
+            .last()
+            .map_or((0, 0), |r| (r.our_total, r.their_total));
+        writeln!(f, "rounds          {}", self.rounds.len())?;
+        writeln!(f, "score           us {ours}, them {theirs}")?;
+        for (side, tally) in [("us", self.ours), ("them", self.theirs)] {
+            writeln!(
+                f,
+                "{side:<15} {} wins, {} draws, {} losses",
+                tally.wins, tally.draws, tally.losses
+            )?;
+        }
+        writeln!(
+            f,
+            "longest streaks {} wins, {} losses",
+            self.longest_win_streak, self.longest_loss_streak
+        )?;
+        write!(f, "they played    ")?;
+        for (name, count) in &self.their_moves {
+            write!(f, " {name} {count}")?;
+        }
+        writeln!(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MatchReport, Tally};
+    use crate::{Game, Interpretation};
+
+    #[test]
+    fn sample_match() {
+        let game = Game::rps();
+        let rounds: Vec<_> = [('A', 'Y'), ('B', 'X'), ('C', 'Z'), ('A', 'Y'), ('A', 'Y')]
+            .into_iter()
+            .map(|columns| Interpretation::Moves.round(&game, columns).unwrap())
+            .collect();
+        let report = MatchReport::new(&game, &rounds);
+
+        let curve: Vec<_> = report.rounds.iter().map(|r| r.our_total).collect();
+        assert_eq!(curve, [8, 9, 15, 23, 31]);
+        assert_eq!(report.rounds[1].their_total, 1 + 8);
+        assert_eq!(
+            report.ours,
+            Tally {
+                wins: 3,
+                draws: 1,
+                losses: 1
+            }
+        );
+        assert_eq!(
+            report.theirs,
+            Tally {
+                wins: 1,
+                draws: 1,
+                losses: 3
+            }
+        );
+        assert_eq!(report.their_moves[0], ("Rock".to_owned(), 3));
+        assert_eq!(
+            (report.longest_win_streak, report.longest_loss_streak),
+            (2, 1)
+        );
+
+        let mut csv = Vec::new();
+        report.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(csv.lines().nth(1), Some("1,Rock,Paper,Win,8,1,8,1"));
+    }
+}
@@ -0,0 +1,221 @@
+//! What's in the rucksacks beyond the priority sums: duplicates, how to
+//! get rid of them, how common each item type is and each group's badge.
+
+use std::fmt;
+
+use crate::{Item, ItemSet, Layout, RucksackError};
+
+/// Moving `count` copies of `item` between compartments, numbered from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub item: Item,
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RucksackReport {
+    /// Numbered from 1, like input lines.
+    pub rucksack: usize,
+    /// Item types found in more than one compartment.
+    pub duplicates: ItemSet,
+    /// The fewest moves leaving every item type in a single compartment.
+    /// Compartments may end up different sizes.
+    pub moves: Vec<Move>,
+}
+
+impl RucksackReport {
+    fn new(layout: &Layout, rucksack: usize, items: &[Item]) -> Result<Self, RucksackError> {
+        let compartments = layout.split(rucksack, items)?;
+        let sets: Vec<ItemSet> = compartments
+            .iter()
+            .map(|c| c.iter().copied().collect())
+            .collect();
+
+        let mut seen = ItemSet::EMPTY;
+        let mut duplicates = ItemSet::EMPTY;
+        for &set in &sets {
+            duplicates = duplicates.union(seen.intersection(set));
+            seen = seen.union(set);
+        }
+
+        // keeping the copies where most of them already are moves the fewest
+        let mut moves = Vec::new();
+        for item in duplicates.iter() {
+            let counts: Vec<usize> = compartments
+                .iter()
+                .map(|c| c.iter().filter(|&&i| i == item).count())
+                .collect();
+            let to = (0..counts.len()).rev().max_by_key(|&i| counts[i]).unwrap();
+            for (from, &count) in counts.iter().enumerate() {
+                if from != to && count > 0 {
+                    moves.push(Move {
+                        item,
+                        count,
+                        from: from + 1,
+                        to: to + 1,
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            rucksack,
+            duplicates,
+            moves,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupReport {
+    /// Numbered from 1.
+    pub group: usize,
+    pub elves: usize,
+    /// Item types every elf of the group carries; the puzzle promises
+    /// exactly one.
+    pub badges: ItemSet,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub group_size: usize,
+    pub rucksacks: Vec<RucksackReport>,
+    /// How many of each item type there are, most common first.
+    pub frequency: Vec<(Item, usize)>,
+    pub groups: Vec<GroupReport>,
+}
+
+impl Analysis {
+    /// Only fails when a rucksack doesn't split into `layout`'s
+    /// compartments; anything else the puzzle rules out is reported.
+    pub fn new(layout: &Layout, rucksacks: &[Vec<Item>]) -> Result<Self, RucksackError> {
+        let reports = (1..)
+            .zip(rucksacks)
+            .map(|(rucksack, items)| RucksackReport::new(layout, rucksack, items))
+            .collect::<Result<_, _>>()?;
+
+        let mut counts = [0; 53];
+        for item in rucksacks.iter().flatten() {
+            counts[item.score()] += 1;
+        }
+        let mut frequency: Vec<_> = (1..counts.len())
+            .filter(|&score| counts[score] > 0)
+            .map(|score| (Item::from_score(score), counts[score]))
+            .collect();
+        frequency.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+
+        let groups = (1..)
+            .zip(rucksacks.chunks(layout.group_size()))
+            .map(|(group, elves)| GroupReport {
+                group,
+                elves: elves.len(),
+                badges: elves
+                    .iter()
+                    .map(|items| items.iter().copied().collect())
+                    .reduce(ItemSet::intersection)
+                    .unwrap_or_default(),
+            })
+            .collect();
+
+        Ok(Self {
+            group_size: layout.group_size(),
+            rucksacks: reports,
+            frequency,
+            groups,
+        })
+    }
+}
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:>8}  {:<12} moves", "rucksack", "duplicates")?;
+        for report in &self.rucksacks {
+            let duplicates: String = report.duplicates.iter().map(|i| format!("{i:?}")).collect();
+            let moves: Vec<_> = report
+                .moves
+                .iter()
+                .map(|m| format!("{}x{:?} {}->{}", m.count, m.item, m.from, m.to))
+                .collect();
+            writeln!(
+                f,
+                "{:>8}  {:<12} {}",
+                report.rucksack,
+                duplicates,
+                moves.join(", ")
+            )?;
+        }
+        let moves: usize = self
+            .rucksacks
+            .iter()
+            .flat_map(|r| &r.moves)
+            .map(|m| m.count)
+            .sum();
+        writeln!(f, "{moves} item(s) to move in all")?;
+
+        writeln!(f)?;
+        writeln!(f, "item frequency")?;
+        for row in self.frequency.chunks(8) {
+            let row: Vec<_> = row
+                .iter()
+                .map(|(item, count)| format!("{item:?} {count:>4}"))
+                .collect();
+            writeln!(f, "  {}", row.join("   "))?;
+        }
+
+        writeln!(f)?;
+        for group in &self.groups {
+            write!(f, "group {:>4}: ", group.group)?;
+            match group.badges.len() {
+                _ if group.elves < self.group_size => writeln!(
+                    f,
+                    "incomplete, {} of {} elves",
+                    group.elves, self.group_size
+                )?,
+                0 => writeln!(f, "no badge")?,
+                1 => writeln!(f, "badge {:?}", group.badges.iter().next().unwrap())?,
+                _ => writeln!(f, "several badges {:?}", group.badges)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Analysis, Move};
+    use crate::{Item, Layout};
+
+    #[test]
+    fn sample_analysis() {
+        let rucksacks: Vec<Vec<Item>> = include_str!("sample_input.txt")
+            .lines()
+            .chain(["aabaab"])
+            .map(|line| line.bytes().map(|b| Item::try_from(b).unwrap()).collect())
+            .collect();
+        let analysis = Analysis::new(&Layout::default(), &rucksacks).unwrap();
+        let item = |b| Item::try_from(b).unwrap();
+
+        // vJrwpWtwJgWr|hcsFMMfFFhFp: the one p on the right moves left
+        assert_eq!(
+            analysis.rucksacks[0].moves,
+            [Move {
+                item: item(b'p'),
+                count: 1,
+                from: 2,
+                to: 1
+            }]
+        );
+        // aab|aab: two a and one b
+        let moves = &analysis.rucksacks[6].moves;
+        assert_eq!(moves.iter().map(|m| m.count).sum::<usize>(), 3);
+        assert_eq!(analysis.rucksacks[6].duplicates.len(), 2);
+
+        assert_eq!(analysis.frequency[0], (item(b'w'), 12));
+        assert_eq!(format!("{:?}", analysis.groups[1].badges), "{Z}");
+        assert!(analysis
+            .to_string()
+            .ends_with("group    3: incomplete, 1 of 3 elves\n"));
+    }
+}
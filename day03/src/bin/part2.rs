@@ -1,62 +0,0 @@
-mod item {
-    #[repr(transparent)]
-    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
-    pub(crate) struct Item(u8);
-
-    impl TryFrom<u8> for Item {
-        type Error = color_eyre::Report;
-
-        fn try_from(value: u8) -> Result<Self, Self::Error> {
-            match value {
-                b'a'..=b'z' | b'A'..=b'Z' => Ok(Item(value)),
-                _ => Err(color_eyre::eyre::eyre!(
-                    "{} is not a valid item",
-                    value as char
-                )),
-            }
-        }
-    }
-
-    impl Item {
-        pub fn score(self) -> usize {
-            match self {
-                Item(b'a'..=b'z') => 1 + (self.0 - b'a') as usize,
-                Item(b'A'..=b'Z') => 27 + (self.0 - b'A') as usize,
-                _ => unreachable!(),
-            }
-        }
-    }
-
-    impl std::fmt::Debug for Item {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            write!(f, "{}", self.0 as char)
-        }
-    }
-}
-
-use std::collections::HashSet;
-
-use item::Item;
-use itertools::Itertools;
-
-fn main() -> color_eyre::Result<()> {
-    let rucksacks = include_str!("../sample_input.txt").lines().map(|line| {
-        line.bytes()
-            .map(Item::try_from)
-            .collect::<Result<HashSet<_>, _>>()
-    });
-
-    let sum = itertools::process_results(rucksacks, |rs| {
-        rs.tuples()
-            .map(|(a, b, c)| {
-                a.iter()
-                    .copied()
-                    .find(|i| b.contains(i) && c.contains(i))
-                    .map(|i| dbg!(i.score()))
-                    .unwrap_or_default()
-            })
-            .sum::<usize>();
-    })?;
-    dbg!(sum);
-    Ok(())
-}
@@ -0,0 +1,54 @@
+//! Random rucksacks, in groups of three sharing exactly one badge.
+
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// `size` rounded up to whole groups. Each rucksack has exactly one item
+/// type in both compartments, and each group exactly one in common.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut out = String::new();
+
+    for _ in 0..size.max(1).div_ceil(3) {
+        let mut items = ITEMS.to_vec();
+        items.shuffle(&mut rng);
+        let (badge, others) = items.split_first().unwrap();
+
+        // every elf draws from its own items, so only the badge is shared
+        for pool in others.chunks(others.len() / 3) {
+            out.push_str(&rucksack(&mut rng, *badge, pool));
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+fn rucksack(rng: &mut ChaCha8Rng, badge: u8, pool: &[u8]) -> String {
+    let shared = if rng.gen_bool(0.2) {
+        badge
+    } else {
+        *pool.choose(rng).unwrap()
+    };
+    let rest: Vec<u8> = pool.iter().copied().filter(|&i| i != shared).collect();
+    let (left_items, right_items) = rest.split_at(rest.len() / 2);
+
+    let len = rng.gen_range(3..=16);
+    let mut left = vec![shared];
+    if shared != badge {
+        left.push(badge);
+    }
+    let mut right = vec![shared];
+    while left.len() < len {
+        left.push(*left_items.choose(rng).unwrap());
+    }
+    while right.len() < len {
+        right.push(*right_items.choose(rng).unwrap());
+    }
+    left.shuffle(rng);
+    right.shuffle(rng);
+
+    left.into_iter().chain(right).map(char::from).collect()
+}
@@ -0,0 +1,42 @@
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Item(u8);
+
+impl TryFrom<u8> for Item {
+    type Error = color_eyre::Report;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'a'..=b'z' | b'A'..=b'Z' => Ok(Item(value)),
+            _ => Err(color_eyre::eyre::eyre!(
+                "{} is not a valid item",
+                value as char
+            )),
+        }
+    }
+}
+
+impl std::fmt::Debug for Item {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0 as char)
+    }
+}
+
+impl Item {
+    pub(crate) fn score(self) -> usize {
+        match self {
+            Item(b'a'..=b'z') => 1 + (self.0 - b'a') as usize,
+            Item(b'A'..=b'Z') => 27 + (self.0 - b'A') as usize,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The item with priority `score`, which must be between 1 and 52.
+    pub(crate) fn from_score(score: usize) -> Self {
+        match score {
+            1..=26 => Item(b'a' + (score - 1) as u8),
+            27..=52 => Item(b'A' + (score - 27) as u8),
+            _ => unreachable!("no item has priority {score}"),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt;
+
+use crate::Item;
+
+/// A set of item types, one bit per priority.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: Self = Self(0);
+
+    pub fn insert(&mut self, item: Item) {
+        self.0 |= 1 << item.score();
+    }
+
+    pub fn contains(self, item: Item) -> bool {
+        self.0 & (1 << item.score()) != 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Items in `self` but not in `other`.
+    pub fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Items by increasing priority.
+    pub fn iter(self) -> impl Iterator<Item = Item> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+            let score = bits.trailing_zeros() as usize;
+            bits &= bits - 1;
+            Some(Item::from_score(score))
+        })
+    }
+}
+
+impl FromIterator<Item> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = Item>>(items: I) -> Self {
+        let mut set = Self::EMPTY;
+        for item in items {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+impl fmt::Debug for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ItemSet;
+    use crate::Item;
+
+    fn set(items: &str) -> ItemSet {
+        items.bytes().map(|b| Item::try_from(b).unwrap()).collect()
+    }
+
+    #[test]
+    fn set_operations() {
+        let (a, b) = (set("abcZ"), set("cdZ"));
+        assert_eq!(a.union(b), set("abcdZ"));
+        assert_eq!(a.intersection(b), set("cZ"));
+        assert_eq!(a.difference(b), set("ab"));
+        assert_eq!(format!("{:?}", a.union(b)), "{a, b, c, d, Z}");
+        assert_eq!(a.iter().map(Item::score).collect::<Vec<_>>(), [1, 2, 3, 52]);
+        assert_eq!(set("aab").len(), 2);
+        assert!(a.difference(a).is_empty());
+    }
+}
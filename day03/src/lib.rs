@@ -0,0 +1,161 @@
+mod analysis;
+pub mod gen;
+mod item;
+mod item_set;
+mod rucksack;
+
+use std::collections::HashSet;
+
+pub use analysis::{Analysis, GroupReport, Move, RucksackReport};
+use aoc_core::{parse::ParseError, Answer, Part, Solution, Variant};
+use color_eyre::eyre::eyre;
+pub use item::Item;
+pub use item_set::ItemSet;
+pub use rucksack::{Layout, RucksackError};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    /// One list of items per rucksack.
+    type Input = Vec<Vec<Item>>;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        let rucksacks = input.lines().map(|line| {
+            line.char_indices()
+                .map(|(i, c)| {
+                    u8::try_from(c)
+                        .ok()
+                        .and_then(|b| Item::try_from(b).ok())
+                        .ok_or_else(|| ParseError::at(input, &line[i..], "an item, a-z or A-Z"))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        });
+        Ok(rucksacks.collect::<Result<_, _>>()?)
+    }
+
+    /// Sums the priorities of the item found in both compartments of each
+    /// rucksack.
+    fn part1(rucksacks: &Self::Input) -> color_eyre::Result<Answer> {
+        let duplicates = Layout::default().duplicates(rucksacks)?;
+        Ok(duplicates
+            .iter()
+            .map(|item| item.score())
+            .sum::<usize>()
+            .into())
+    }
+
+    /// Sums the priorities of the badge shared by each group of three elves.
+    fn part2(rucksacks: &Self::Input) -> color_eyre::Result<Answer> {
+        let badges = Layout::default().badges(rucksacks)?;
+        Ok(badges.iter().map(|item| item.score()).sum::<usize>().into())
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant {
+                part: Part::One,
+                name: "bitset",
+                solve: Self::part1,
+            },
+            Variant {
+                part: Part::One,
+                name: "hashset",
+                solve: |rucksacks| part1_hashset(rucksacks),
+            },
+            Variant {
+                part: Part::Two,
+                name: "bitset",
+                solve: Self::part2,
+            },
+            Variant {
+                part: Part::Two,
+                name: "im",
+                solve: |rucksacks| part2_im(rucksacks),
+            },
+        ]
+    }
+}
+
+/// Part 1 with a `HashSet` per rucksack, for comparison.
+fn part1_hashset(rucksacks: &[Vec<Item>]) -> color_eyre::Result<Answer> {
+    rucksacks
+        .iter()
+        .map(|items| {
+            let (first, second) = items.split_at(items.len() / 2);
+            let first_items = first.iter().copied().collect::<HashSet<_>>();
+
+            second
+                .iter()
+                .find(|item| first_items.contains(item))
+                .map(|item| item.score())
+                .ok_or_else(|| eyre!("compartments have no items in common"))
+        })
+        .sum::<color_eyre::Result<usize>>()
+        .map(Answer::from)
+}
+
+/// Part 2 intersecting persistent `im` sets, for comparison.
+fn part2_im(rucksacks: &[Vec<Item>]) -> color_eyre::Result<Answer> {
+    let sum = rucksacks
+        .chunks(3)
+        .map(|chunks| {
+            chunks
+                .iter()
+                .map(|items| items.iter().copied().collect::<im::HashSet<Item>>())
+                .reduce(|a, b| a.intersection(b))
+                .and_then(|common| common.iter().next().map(|item| item.score()))
+                .ok_or_else(|| eyre!("group has no badge in common"))
+        })
+        .sum::<color_eyre::Result<usize>>()?;
+
+    Ok(sum.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{solve, Part, Solution};
+    use proptest::prelude::*;
+
+    use crate::{gen, Day3};
+
+    #[test]
+    fn sample_input() {
+        let input = include_str!("sample_input.txt");
+        assert_eq!(
+            solve::<Day3>(input, Part::One).unwrap().answer,
+            157_usize.into()
+        );
+        assert_eq!(
+            solve::<Day3>(input, Part::Two).unwrap().answer,
+            70_usize.into()
+        );
+    }
+
+    #[test]
+    fn positioned_errors() {
+        let error = Day3::parse("abAB\nab1c\n").unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "line 2, column 3: expected an item, a-z or A-Z\n  |\n2 | ab1c\n  |   ^"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn variants_agree(seed in any::<u64>(), size in 1_usize..60) {
+            let rucksacks = Day3::parse(&gen::generate(seed, size)).unwrap();
+            for part in [Part::One, Part::Two] {
+                let answers: Vec<_> = Day3::variants()
+                    .into_iter()
+                    .filter(|v| v.part == part)
+                    .map(|v| (v.name, (v.solve)(&rucksacks).unwrap()))
+                    .collect();
+                prop_assert_eq!(answers.len(), 2);
+                let (_, bitset) = &answers[0];
+                for (name, answer) in &answers {
+                    prop_assert_eq!(answer, bitset, "part {} {}", part, name);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! Splitting rucksacks into compartments and elves into groups, for any
+//! number of either.
+
+use crate::{Item, ItemSet};
+
+/// Rucksacks and groups are numbered from 1, like input lines.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RucksackError {
+    #[error("rucksacks need at least one compartment and groups at least one elf")]
+    EmptyLayout,
+    #[error("rucksack {rucksack}: {len} items don't split into {compartments} equal compartments")]
+    UnevenCompartments {
+        rucksack: usize,
+        len: usize,
+        compartments: usize,
+    },
+    #[error("rucksack {rucksack}: compartments have no item in common")]
+    NoCommonItem { rucksack: usize },
+    #[error("rucksack {rucksack}: compartments have {items:?} in common, not just one item")]
+    MultipleCommonItems { rucksack: usize, items: ItemSet },
+    #[error("group {group}: rucksacks have no badge in common")]
+    NoBadge { group: usize },
+    #[error("group {group}: rucksacks have {items:?} in common, not just one badge")]
+    MultipleBadges { group: usize, items: ItemSet },
+    #[error("group {group} has only {len} of {size} elves")]
+    IncompleteGroup {
+        group: usize,
+        len: usize,
+        size: usize,
+    },
+}
+
+/// How many compartments each rucksack has and how many elves each group
+/// has. The puzzle's is two and three.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layout {
+    compartments: usize,
+    group_size: usize,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            compartments: 2,
+            group_size: 3,
+        }
+    }
+}
+
+impl Layout {
+    pub fn new(compartments: usize, group_size: usize) -> Result<Self, RucksackError> {
+        if compartments == 0 || group_size == 0 {
+            return Err(RucksackError::EmptyLayout);
+        }
+        Ok(Self {
+            compartments,
+            group_size,
+        })
+    }
+
+    /// Rucksack number `rucksack`'s items, split into compartments.
+    pub fn split<'a>(
+        &self,
+        rucksack: usize,
+        items: &'a [Item],
+    ) -> Result<Vec<&'a [Item]>, RucksackError> {
+        if !items.len().is_multiple_of(self.compartments) {
+            return Err(RucksackError::UnevenCompartments {
+                rucksack,
+                len: items.len(),
+                compartments: self.compartments,
+            });
+        }
+        let mut compartments: Vec<_> = items
+            .chunks((items.len() / self.compartments).max(1))
+            .collect();
+        compartments.resize(self.compartments, &[]);
+        Ok(compartments)
+    }
+
+    /// The item types in each compartment of rucksack number `rucksack`.
+    pub fn compartments(
+        &self,
+        rucksack: usize,
+        items: &[Item],
+    ) -> Result<Vec<ItemSet>, RucksackError> {
+        let compartments = self.split(rucksack, items)?;
+        Ok(compartments
+            .into_iter()
+            .map(|c| c.iter().copied().collect())
+            .collect())
+    }
+
+    pub fn group_size(&self) -> usize {
+        self.group_size
+    }
+
+    /// The one item found in every compartment of rucksack number
+    /// `rucksack`.
+    pub fn duplicate(&self, rucksack: usize, items: &[Item]) -> Result<Item, RucksackError> {
+        let common = intersection(self.compartments(rucksack, items)?);
+        match common.len() {
+            0 => Err(RucksackError::NoCommonItem { rucksack }),
+            1 => Ok(common.iter().next().unwrap()),
+            _ => Err(RucksackError::MultipleCommonItems {
+                rucksack,
+                items: common,
+            }),
+        }
+    }
+
+    /// Every rucksack's duplicate, in order.
+    pub fn duplicates(&self, rucksacks: &[Vec<Item>]) -> Result<Vec<Item>, RucksackError> {
+        (1..)
+            .zip(rucksacks)
+            .map(|(rucksack, items)| self.duplicate(rucksack, items))
+            .collect()
+    }
+
+    /// The one item carried by every elf of each group, in order.
+    pub fn badges(&self, rucksacks: &[Vec<Item>]) -> Result<Vec<Item>, RucksackError> {
+        (1..)
+            .zip(rucksacks.chunks(self.group_size))
+            .map(|(group, elves)| {
+                if elves.len() < self.group_size {
+                    return Err(RucksackError::IncompleteGroup {
+                        group,
+                        len: elves.len(),
+                        size: self.group_size,
+                    });
+                }
+                let common = intersection(elves.iter().map(|e| e.iter().copied().collect()));
+                match common.len() {
+                    0 => Err(RucksackError::NoBadge { group }),
+                    1 => Ok(common.iter().next().unwrap()),
+                    _ => Err(RucksackError::MultipleBadges {
+                        group,
+                        items: common,
+                    }),
+                }
+            })
+            .collect()
+    }
+}
+
+fn intersection(sets: impl IntoIterator<Item = ItemSet>) -> ItemSet {
+    sets.into_iter()
+        .reduce(ItemSet::intersection)
+        .unwrap_or(ItemSet::EMPTY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Layout, RucksackError};
+    use crate::{Item, ItemSet};
+
+    fn rucksacks(input: &str) -> Vec<Vec<Item>> {
+        input
+            .lines()
+            .map(|line| line.bytes().map(|b| Item::try_from(b).unwrap()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn layouts() {
+        let three = Layout::new(3, 2).unwrap();
+        let sacks = rucksacks("abcadeafg\nxyxyxy\nAa\n");
+        assert_eq!(
+            three.duplicate(1, &sacks[0]),
+            Ok(Item::try_from(b'a').unwrap())
+        );
+        assert_eq!(
+            three.badges(&sacks[..2]),
+            Err(RucksackError::NoBadge { group: 1 })
+        );
+        assert_eq!(
+            three.duplicates(&sacks),
+            Err(RucksackError::MultipleCommonItems {
+                rucksack: 2,
+                items: sacks[1][..2].iter().copied().collect::<ItemSet>(),
+            })
+        );
+
+        let puzzle = Layout::default();
+        assert_eq!(
+            puzzle.duplicate(4, &sacks[0]),
+            Err(RucksackError::UnevenCompartments {
+                rucksack: 4,
+                len: 9,
+                compartments: 2
+            })
+        );
+        assert_eq!(
+            puzzle.badges(&sacks),
+            Err(RucksackError::NoBadge { group: 1 })
+        );
+        assert_eq!(
+            puzzle.badges(&sacks[..2]),
+            Err(RucksackError::IncompleteGroup {
+                group: 1,
+                len: 2,
+                size: 3
+            })
+        );
+        assert_eq!(
+            puzzle.duplicate(3, &sacks[2]).unwrap_err().to_string(),
+            "rucksack 3: compartments have no item in common"
+        );
+        assert_eq!(Layout::new(0, 3), Err(RucksackError::EmptyLayout));
+    }
+}
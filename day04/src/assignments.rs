@@ -0,0 +1,227 @@
+//! Every elf's assignment at once, for questions across the whole input:
+//! who covers a section, which elves overlap, how deep coverage goes.
+
+use std::{cmp::Reverse, collections::BinaryHeap, ops::RangeInclusive};
+
+use crate::InclusiveRangeExt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment {
+    /// Numbered from 0, in input order.
+    pub elf: usize,
+    /// Input line, from 1.
+    pub line: usize,
+    pub sections: RangeInclusive<u32>,
+}
+
+/// An interval tree: assignments sorted by start, where the middle of any
+/// `lo..hi` is the root of the subtree holding the rest of `lo..hi`.
+#[derive(Debug, Clone)]
+pub struct Assignments {
+    by_start: Vec<Assignment>,
+    /// The last section anyone in the subtree rooted at each index covers.
+    max_end: Vec<u32>,
+}
+
+impl Assignments {
+    /// Empty assignments (with start after end) cover nothing and are left
+    /// out.
+    pub fn new(assignments: impl IntoIterator<Item = Assignment>) -> Self {
+        let mut by_start: Vec<_> = assignments
+            .into_iter()
+            .filter(|a| !a.sections.is_empty())
+            .collect();
+        by_start.sort_by_key(|a| (*a.sections.start(), a.elf));
+
+        let mut tree = Self {
+            max_end: vec![0; by_start.len()],
+            by_start,
+        };
+        tree.build(0, tree.by_start.len());
+        tree
+    }
+
+    /// Each line's assignments, numbering elves on from one line to the
+    /// next.
+    pub fn from_lines(lines: &[Vec<RangeInclusive<u32>>]) -> Self {
+        let assignments = (1..)
+            .zip(lines)
+            .flat_map(|(line, ranges)| ranges.iter().map(move |sections| (line, sections.clone())));
+        Self::new(
+            assignments
+                .enumerate()
+                .map(|(elf, (line, sections))| Assignment {
+                    elf,
+                    line,
+                    sections,
+                }),
+        )
+    }
+
+    /// Each line's pair, as elves `2 * (line - 1)` and the one after.
+    pub fn from_pairs(pairs: &[(RangeInclusive<u32>, RangeInclusive<u32>)]) -> Self {
+        let lines: Vec<_> = pairs
+            .iter()
+            .map(|(a, b)| vec![a.clone(), b.clone()])
+            .collect();
+        Self::from_lines(&lines)
+    }
+
+    fn build(&mut self, lo: usize, hi: usize) -> Option<u32> {
+        if lo >= hi {
+            return None;
+        }
+        let mid = (lo + hi) / 2;
+        let max_end = [
+            self.build(lo, mid),
+            Some(*self.by_start[mid].sections.end()),
+            self.build(mid + 1, hi),
+        ]
+        .into_iter()
+        .flatten()
+        .max()?;
+        self.max_end[mid] = max_end;
+        Some(max_end)
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_start.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_start.is_empty()
+    }
+
+    /// Assignments sharing at least one section with `range`, by elf.
+    pub fn overlapping(&self, range: &RangeInclusive<u32>) -> Vec<&Assignment> {
+        let mut found = Vec::new();
+        if !range.is_empty() {
+            self.visit(0, self.by_start.len(), range, &mut found);
+        }
+        found.sort_by_key(|a| a.elf);
+        found
+    }
+
+    /// Assignments including `section`, by elf.
+    pub fn covering(&self, section: u32) -> Vec<&Assignment> {
+        self.overlapping(&(section..=section))
+    }
+
+    fn visit<'a>(
+        &'a self,
+        lo: usize,
+        hi: usize,
+        range: &RangeInclusive<u32>,
+        found: &mut Vec<&'a Assignment>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        // nothing in this subtree reaches `range`
+        if self.max_end[mid] < *range.start() {
+            return;
+        }
+        self.visit(lo, mid, range, found);
+        let assignment = &self.by_start[mid];
+        if assignment.sections.overlaps_or_is_overlapped(range) {
+            found.push(assignment);
+        }
+        // everything to the right starts later still
+        if assignment.sections.start() <= range.end() {
+            self.visit(mid + 1, hi, range, found);
+        }
+    }
+
+    /// Every pair of elves whose assignments overlap, as `(elf, elf)` with
+    /// the smaller first, sorted. A sweep over the starts, so it takes
+    /// O(n log n) plus the number of pairs.
+    pub fn overlapping_pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        // ends of the assignments the sweep is inside, earliest first
+        let mut active: BinaryHeap<Reverse<(u32, usize)>> = BinaryHeap::new();
+        for assignment in &self.by_start {
+            let start = *assignment.sections.start();
+            while active.peek().is_some_and(|&Reverse((end, _))| end < start) {
+                active.pop();
+            }
+            for &Reverse((_, elf)) in &active {
+                pairs.push((elf.min(assignment.elf), elf.max(assignment.elf)));
+            }
+            active.push(Reverse((*assignment.sections.end(), assignment.elf)));
+        }
+        pairs.sort_unstable();
+        pairs
+    }
+
+    /// The most elves covering any one section, and the first section where
+    /// that many do. `None` without assignments.
+    pub fn max_depth(&self) -> Option<(usize, u32)> {
+        // leaving happens just after the end, before anyone starting there
+        let mut events: Vec<(u64, isize)> = self
+            .by_start
+            .iter()
+            .flat_map(|a| {
+                [
+                    (u64::from(*a.sections.start()), 1),
+                    (u64::from(*a.sections.end()) + 1, -1),
+                ]
+            })
+            .collect();
+        events.sort_unstable();
+
+        let mut depth = 0_usize;
+        let mut deepest = None;
+        for (section, change) in events {
+            depth = depth.wrapping_add_signed(change);
+            if deepest.is_none_or(|(d, _)| depth > d) {
+                deepest = Some((depth, section as u32));
+            }
+        }
+        deepest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::RangeInclusive;
+
+    use proptest::prelude::*;
+
+    use super::Assignments;
+    use crate::InclusiveRangeExt;
+
+    fn pairs() -> impl Strategy<Value = Vec<(RangeInclusive<u32>, RangeInclusive<u32>)>> {
+        let range = || (0_u32..50, 0_u32..10).prop_map(|(start, len)| start..=start + len);
+        prop::collection::vec((range(), range()), 0..30)
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(pairs in pairs(), section in 0_u32..60) {
+            let assignments = Assignments::from_pairs(&pairs);
+            let ranges: Vec<_> = pairs.iter().flat_map(|(a, b)| [a.clone(), b.clone()]).collect();
+
+            let covering: Vec<_> = assignments.covering(section).iter().map(|a| a.elf).collect();
+            let expected: Vec<_> = (0..ranges.len()).filter(|&e| ranges[e].contains(&section)).collect();
+            prop_assert_eq!(covering, expected);
+
+            let mut expected = Vec::new();
+            for a in 0..ranges.len() {
+                for b in a + 1..ranges.len() {
+                    if ranges[a].overlaps_or_is_overlapped(&ranges[b]) {
+                        expected.push((a, b));
+                    }
+                }
+            }
+            prop_assert_eq!(assignments.overlapping_pairs(), expected);
+
+            let depth = |s: u32| ranges.iter().filter(|r| r.contains(&s)).count();
+            let deepest = (0..60).map(depth).max().filter(|&d| d > 0);
+            prop_assert_eq!(assignments.max_depth().map(|(d, _)| d), deepest);
+            if let Some((d, s)) = assignments.max_depth() {
+                prop_assert_eq!(depth(s), d);
+            }
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! Pictures of section assignments: the puzzle's dotted timelines for a
+//! terminal, and SVG for inputs too big to read that way.
+
+use std::{
+    fmt::{self, Write},
+    ops::RangeInclusive,
+};
+
+use crate::InclusiveRangeExt;
+
+type Pair = (RangeInclusive<u32>, RangeInclusive<u32>);
+
+/// From section 1, or 0 if anybody is assigned it, to the last one anybody
+/// is assigned.
+fn bounds(pairs: &[Pair]) -> RangeInclusive<u32> {
+    let ranges = || pairs.iter().flat_map(|(a, b)| [a, b]);
+    let first = ranges().map(|r| *r.start()).fold(1, u32::min);
+    let last = ranges().map(|r| *r.end()).max().unwrap_or(first);
+    first..=last.max(first)
+}
+
+/// `.234.....` for `2-4` within `1..=9`: the last digit of each section the
+/// range covers, dots elsewhere.
+pub fn timeline(range: &RangeInclusive<u32>, bounds: &RangeInclusive<u32>) -> String {
+    bounds
+        .clone()
+        .map(|section| match range.contains(&section) {
+            true => char::from_digit(section % 10, 10).unwrap(),
+            false => '.',
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Relation {
+    Contains,
+    Overlaps,
+    Apart,
+}
+
+impl Relation {
+    fn of(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> Self {
+        if a.contains_or_is_contained(b) {
+            Relation::Contains
+        } else if a.overlaps_or_is_overlapped(b) {
+            Relation::Overlaps
+        } else {
+            Relation::Apart
+        }
+    }
+}
+
+/// Pairs with their input line numbers, drawn as in the puzzle statement,
+/// with a row of `^` under the sections both elves cover.
+pub struct Drawing<'a> {
+    pairs: Vec<(usize, &'a Pair)>,
+    /// Shared by every pair drawn, even when only some are.
+    bounds: RangeInclusive<u32>,
+}
+
+impl<'a> Drawing<'a> {
+    /// `pairs` in input order, so line numbers are counted from 1.
+    pub fn new(pairs: &'a [Pair]) -> Self {
+        Self {
+            pairs: (1..).zip(pairs).collect(),
+            bounds: bounds(pairs),
+        }
+    }
+
+    /// Only the pairs on the given lines.
+    pub fn lines(mut self, lines: &[usize]) -> Self {
+        self.pairs.retain(|(line, _)| lines.contains(line));
+        self
+    }
+
+    pub fn svg(&self) -> String {
+        // in u64, as sections go up to u32::MAX
+        const SECTION: u64 = 6;
+        const ROW: u64 = 5;
+        let (first, last) = (
+            u64::from(*self.bounds.start()),
+            u64::from(*self.bounds.end()),
+        );
+        let width = SECTION * (last - first + 1);
+        let height = ROW * 3 * self.pairs.len() as u64;
+
+        let mut svg = String::new();
+        let mut bar = |range: &RangeInclusive<u32>, row: u64, fill: &str, title: &str| {
+            if range.is_empty() {
+                return;
+            }
+            let (start, end) = (u64::from(*range.start()), u64::from(*range.end()));
+            let x = SECTION * (start - first);
+            let w = SECTION * (end - start + 1);
+            writeln!(
+                svg,
+                r#"  <rect x="{x}" y="{}" width="{w}" height="{}" fill="{fill}"><title>{title}</title></rect>"#,
+                row * ROW,
+                ROW - 1
+            )
+            .unwrap();
+        };
+
+        for (i, (line, (a, b))) in self.pairs.iter().enumerate() {
+            let row = 3 * i as u64;
+            let title = format!(
+                "line {line}: {}-{},{}-{}",
+                a.start(),
+                a.end(),
+                b.start(),
+                b.end()
+            );
+            let relation = Relation::of(a, b);
+            // the contained one in red
+            let (a_fill, b_fill) = match relation {
+                Relation::Contains if a.contains_range(b) => ("#9ab", "#c33"),
+                Relation::Contains => ("#c33", "#9ab"),
+                _ => ("#9ab", "#9ab"),
+            };
+            bar(a, row, a_fill, &title);
+            bar(b, row + 1, b_fill, &title);
+            if relation != Relation::Apart {
+                let both = *a.start().max(b.start())..=*a.end().min(b.end());
+                bar(&both, row, "#e80", &title);
+                bar(&both, row + 1, "#e80", &title);
+            }
+        }
+
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\">\n{svg}</svg>\n"
+        )
+    }
+}
+
+impl fmt::Display for Drawing<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bounds = &self.bounds;
+        for (line, (a, b)) in &self.pairs {
+            writeln!(f, "line {line}")?;
+            writeln!(f, "{}  {}-{}", timeline(a, bounds), a.start(), a.end())?;
+            writeln!(f, "{}  {}-{}", timeline(b, bounds), b.start(), b.end())?;
+            let label = match Relation::of(a, b) {
+                Relation::Contains => "contains",
+                Relation::Overlaps => "overlaps",
+                Relation::Apart => continue,
+            };
+            let both: String = bounds
+                .clone()
+                .map(|s| match a.contains(&s) && b.contains(&s) {
+                    true => '^',
+                    false => ' ',
+                })
+                .collect();
+            writeln!(f, "{both}  {label}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Drawing;
+    use crate::Day4;
+    use aoc_core::Solution;
+
+    #[test]
+    fn sample_drawing() {
+        let pairs = Day4::parse(include_str!("sample_input.txt")).unwrap();
+        let drawing = Drawing::new(&pairs).lines(&[1, 4]);
+        assert_eq!(
+            drawing.to_string(),
+            "line 1\n\
+             .234.....  2-4\n\
+             .....678.  6-8\n\
+             line 4\n\
+             .2345678.  2-8\n\
+             ..34567..  3-7\n  \
+             ^^^^^    contains\n"
+        );
+
+        let svg = drawing.svg();
+        assert!(
+            svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="54" height="30""#)
+        );
+        assert_eq!(svg.matches("<rect").count(), 6);
+        assert!(svg.contains(r##"fill="#c33"><title>line 4: 2-8,3-7</title>"##));
+    }
+
+    #[test]
+    fn any_sections() {
+        let pairs = [(0..=3, 2..=5)];
+        assert_eq!(
+            Drawing::new(&pairs).to_string(),
+            "line 1\n\
+             0123..  0-3\n\
+             ..2345  2-5\n  \
+             ^^    overlaps\n"
+        );
+        assert!(Drawing::new(&pairs)
+            .svg()
+            .contains(r#"<rect x="0" y="0" width="24""#));
+
+        let pairs = [(1..=4_000_000_000, 2..=5)];
+        assert!(Drawing::new(&pairs)
+            .svg()
+            .contains(r#"width="24000000000" height="15""#));
+    }
+}
@@ -0,0 +1,19 @@
+//! Random section assignment pairs.
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// `size` pairs of ranges within sections 1 to 99.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut range = move || {
+        let start = rng.gen_range(1..=99);
+        (start, rng.gen_range(start..=99))
+    };
+    (0..size.max(1))
+        .map(|_| {
+            let ((a, b), (c, d)) = (range(), range());
+            format!("{a}-{b},{c}-{d}\n")
+        })
+        .collect()
+}
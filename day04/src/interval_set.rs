@@ -0,0 +1,247 @@
+//! Sets of sections stored as sorted, disjoint ranges.
+
+use std::ops::RangeInclusive;
+
+use crate::InclusiveRangeExt;
+
+/// Integers an [`IntervalSet`] can hold. Merging touching ranges and taking
+/// complements needs each value's neighbours. Only types of at most 32 bits,
+/// so that [`IntervalSet::len`] always fits its `u64`.
+pub trait Step: Copy + Ord {
+    fn next(self) -> Option<Self>;
+    fn prev(self) -> Option<Self>;
+    /// How many values `start..=end` holds.
+    fn count(start: Self, end: Self) -> u64;
+}
+
+macro_rules! impl_step {
+    ($($t:ty),*) => {
+        $(impl Step for $t {
+            fn next(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn prev(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn count(start: Self, end: Self) -> u64 {
+                (end as i128 - start as i128 + 1) as u64
+            }
+        })*
+    };
+}
+
+impl_step!(u8, u16, u32, i32);
+
+/// Ranges kept sorted, non-empty, and neither overlapping nor touching, so
+/// each set has exactly one representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Step> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    /// Empty ranges (with start after end) are left out.
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut ranges: Vec<_> = ranges.into_iter().filter(|r| !r.is_empty()).collect();
+        ranges.sort_by_key(|r| *r.start());
+
+        let mut merged: Vec<RangeInclusive<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if touches(last, &range) => {
+                    *last = *last.start()..=*last.end().max(range.end());
+                }
+                _ => merged.push(range),
+            }
+        }
+        Self { ranges: merged }
+    }
+}
+
+/// Whether `b`, starting no earlier than `a`, overlaps it or starts right
+/// after it.
+fn touches<T: Step>(a: &RangeInclusive<T>, b: &RangeInclusive<T>) -> bool {
+    a.overlaps_or_is_overlapped(b) || a.end().next() == Some(*b.start())
+}
+
+impl<T: Step> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ranges(&self) -> &[RangeInclusive<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many values the set holds.
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|r| T::count(*r.start(), *r.end()))
+            .sum()
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        *self = self.ranges.iter().cloned().chain([range]).collect();
+    }
+
+    /// The range holding `value`, if any does.
+    pub fn range_of(&self, value: T) -> Option<&RangeInclusive<T>> {
+        let i = self.ranges.partition_point(|r| *r.end() < value);
+        self.ranges.get(i).filter(|r| r.contains(&value))
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.range_of(value).is_some()
+    }
+
+    /// Whether every value of `range` is in the set.
+    pub fn contains_range(&self, range: &RangeInclusive<T>) -> bool {
+        range.is_empty()
+            || self
+                .range_of(*range.start())
+                .is_some_and(|r| r.contains_range(range))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        let mut ranges = Vec::new();
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            if x.overlaps_or_is_overlapped(y) {
+                ranges.push(*x.start().max(y.start())..=*x.end().min(y.end()));
+            }
+            // whichever ends first can't overlap anything else
+            if x.end() < y.end() {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Values in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(first), Some(last)) => {
+                self.intersection(&other.complement(*first.start()..=*last.end()))
+            }
+            _ => Self::new(),
+        }
+    }
+
+    /// Values within `bounds` that aren't in the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        if bounds.is_empty() {
+            return Self::new();
+        }
+        let mut ranges = Vec::new();
+        // the first value of `bounds` not yet known to be in or out
+        let mut from = Some(*bounds.start());
+        for range in &self.ranges {
+            let Some(start) = from else { break };
+            if range.start() > bounds.end() {
+                break;
+            }
+            if *range.start() > start {
+                ranges.push(start..=range.start().prev().unwrap());
+            }
+            if *range.end() >= start {
+                from = range.end().next();
+            }
+        }
+        if let Some(start) = from {
+            ranges.push(start..=*bounds.end());
+        }
+        ranges.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::RangeInclusive;
+
+    use proptest::prelude::*;
+
+    use super::IntervalSet;
+
+    /// The same set as one bit per value, to check against.
+    fn bits(set: &IntervalSet<u8>) -> u64 {
+        set.ranges()
+            .iter()
+            .flat_map(|r| r.clone())
+            .fold(0, |bits, v| bits | 1 << v)
+    }
+
+    fn set() -> impl Strategy<Value = (Vec<RangeInclusive<u8>>, IntervalSet<u8>)> {
+        prop::collection::vec((0_u8..64, 0_u8..64).prop_map(|(a, b)| a..=b), 0..6)
+            .prop_map(|ranges| (ranges.clone(), ranges.into_iter().collect()))
+    }
+
+    #[test]
+    fn merging() {
+        let set: IntervalSet<u32> = [
+            5..=7,
+            1..=2,
+            3..=3,
+            RangeInclusive::new(9, 8),
+            10..=12,
+            11..=11,
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.ranges(), [1..=3, 5..=7, 10..=12]);
+        assert_eq!(set.len(), 9);
+        assert_eq!(
+            set.complement(0..=20).ranges(),
+            [0..=0, 4..=4, 8..=9, 13..=20]
+        );
+        assert_eq!(set.complement(2..=6).ranges(), [4..=4]);
+        assert!(set.contains_range(&(5..=7)) && !set.contains_range(&(5..=8)));
+
+        let full: IntervalSet<u8> = [0..=u8::MAX].into_iter().collect();
+        assert!(full.complement(0..=u8::MAX).is_empty());
+
+        let full: IntervalSet<u32> = [0..=u32::MAX].into_iter().collect();
+        assert_eq!(full.len(), 1 << 32);
+        let full: IntervalSet<i32> = [i32::MIN..=i32::MAX].into_iter().collect();
+        assert_eq!(full.len(), 1 << 32);
+    }
+
+    proptest! {
+        #[test]
+        fn set_operations_match_bits((ranges, a) in set(), (_, b) in set()) {
+            let expected = ranges.iter().flat_map(|r| r.clone()).fold(0_u64, |bits, v| bits | 1 << v);
+            prop_assert_eq!(bits(&a), expected);
+            prop_assert_eq!(a.len(), u64::from(expected.count_ones()));
+            prop_assert!(a.ranges().windows(2).all(|w| *w[0].end() + 1 < *w[1].start()));
+
+            prop_assert_eq!(bits(&a.union(&b)), bits(&a) | bits(&b));
+            prop_assert_eq!(bits(&a.intersection(&b)), bits(&a) & bits(&b));
+            prop_assert_eq!(bits(&a.difference(&b)), bits(&a) & !bits(&b));
+            prop_assert_eq!(bits(&a.complement(0..=63)), !bits(&a));
+            for v in 0..64 {
+                prop_assert_eq!(a.contains(v), bits(&a) & 1 << v != 0);
+            }
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use aoc_core::{
+    parse::{number, parse_lines, token, PResult, ParseError},
+    Answer, Solution,
+};
+use color_eyre::eyre::eyre;
+use nom::{
+    combinator::{map, verify},
+    error::context,
+    multi::separated_list1,
+    sequence::separated_pair,
+};
+
+mod assignments;
+mod draw;
+pub mod gen;
+mod interval_set;
+
+pub use assignments::{Assignment, Assignments};
+pub use draw::{timeline, Drawing};
+pub use interval_set::{IntervalSet, Step};
+
+trait InclusiveRangeExt {
+    fn contains_range(&self, other: &Self) -> bool;
+
+    fn contains_or_is_contained(&self, other: &Self) -> bool {
+        self.contains_range(other) || other.contains_range(self)
+    }
+
+    fn overlaps(&self, other: &Self) -> bool;
+
+    fn overlaps_or_is_overlapped(&self, other: &Self) -> bool {
+        self.overlaps(other) || other.overlaps(self)
+    }
+}
+
+impl<T> InclusiveRangeExt for RangeInclusive<T>
+where
+    T: PartialOrd,
+{
+    fn contains_range(&self, other: &Self) -> bool {
+        self.contains(other.start()) && self.contains(other.end())
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        self.contains(other.start()) || self.contains(other.end())
+    }
+}
+
+/// What to do with ranges like `8-2`, whose start is after their end.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Reversed {
+    /// Fail to parse them.
+    #[default]
+    Reject,
+    /// Read `8-2` as `2-8`.
+    Normalise,
+}
+
+impl FromStr for Reversed {
+    type Err = color_eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "reject" => Ok(Reversed::Reject),
+            "normalise" | "normalize" => Ok(Reversed::Normalise),
+            _ => Err(eyre!("expected reject or normalise, got {s:?}")),
+        }
+    }
+}
+
+fn parse_range<'a>(reversed: Reversed) -> impl FnMut(&'a str) -> PResult<'a, RangeInclusive<u32>> {
+    move |i| {
+        let bounds = separated_pair(number, token("-"), number);
+        match reversed {
+            Reversed::Reject => context(
+                "a range starting no later than it ends",
+                map(
+                    verify(bounds, |(start, end)| start <= end),
+                    |(start, end)| start..=end,
+                ),
+            )(i),
+            Reversed::Normalise => map(bounds, |(start, end): (u32, u32)| {
+                start.min(end)..=start.max(end)
+            })(i),
+        }
+    }
+}
+
+fn parse_pair(i: &str) -> PResult<'_, (RangeInclusive<u32>, RangeInclusive<u32>)> {
+    separated_pair(
+        parse_range(Reversed::Reject),
+        token(","),
+        parse_range(Reversed::Reject),
+    )(i)
+}
+
+/// Any number of comma-separated assignments per line, like `2-4,6-8,1-3`.
+pub fn parse_assignments(
+    input: &str,
+    reversed: Reversed,
+) -> Result<Vec<Vec<RangeInclusive<u32>>>, ParseError> {
+    parse_lines(input, separated_list1(token(","), parse_range(reversed)))
+}
+
+/// Every section assigned to at least one elf.
+pub fn coverage(pairs: &[(RangeInclusive<u32>, RangeInclusive<u32>)]) -> IntervalSet<u32> {
+    pairs
+        .iter()
+        .flat_map(|(a, b)| [a.clone(), b.clone()])
+        .collect()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    /// Each line's pair of section assignments.
+    type Input = Vec<(RangeInclusive<u32>, RangeInclusive<u32>)>;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(parse_lines(input, parse_pair)?)
+    }
+
+    /// Counts the pairs where one assignment fully contains the other.
+    fn part1(pairs: &Self::Input) -> color_eyre::Result<Answer> {
+        Ok(pairs
+            .iter()
+            .filter(|(a, b)| a.contains_or_is_contained(b))
+            .count()
+            .into())
+    }
+
+    /// Counts the pairs whose assignments overlap at all.
+    fn part2(pairs: &Self::Input) -> color_eyre::Result<Answer> {
+        Ok(pairs
+            .iter()
+            .filter(|(a, b)| a.overlaps_or_is_overlapped(b))
+            .count()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::RangeInclusive;
+
+    use aoc_core::Solution;
+    use proptest::prelude::*;
+
+    use crate::{coverage, parse_assignments, Day4, InclusiveRangeExt, Reversed};
+
+    fn range() -> impl Strategy<Value = RangeInclusive<u32>> {
+        (0_u32..100, 0_u32..20).prop_map(|(start, len)| start..=start + len)
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = Day4::parse("2-4,6-8\n2-3,5-4\n").unwrap_err();
+        assert_eq!(
+            error.to_string().lines().next(),
+            Some("line 2, column 5: expected a range starting no later than it ends")
+        );
+        let error = Day4::parse("2-4,6-8,1-1\n").unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 1, column 8: expected end of line"));
+
+        let lines = parse_assignments("8-2,3-3,1-9\n4-5\n", Reversed::Normalise).unwrap();
+        assert_eq!(lines, [vec![2..=8, 3..=3, 1..=9], vec![4..=5]]);
+        assert!(parse_assignments("8-2\n", Reversed::Reject).is_err());
+    }
+
+    #[test]
+    fn unassigned_sections() {
+        let pairs = Day4::parse(include_str!("sample_input.txt")).unwrap();
+        let covered = coverage(&pairs);
+        assert_eq!(covered.ranges(), [2..=9]);
+        assert_eq!(covered.complement(1..=10).ranges(), [1..=1, 10..=10]);
+    }
+
+    proptest! {
+        #[test]
+        fn containment_matches_bounds(a in range(), b in range()) {
+            let expected = (a.start() <= b.start() && b.end() <= a.end())
+                || (b.start() <= a.start() && a.end() <= b.end());
+            prop_assert_eq!(a.contains_or_is_contained(&b), expected);
+        }
+
+        #[test]
+        fn overlap_matches_bounds(a in range(), b in range()) {
+            let expected = a.start() <= b.end() && b.start() <= a.end();
+            prop_assert_eq!(a.overlaps_or_is_overlapped(&b), expected);
+            prop_assert_eq!(a.overlaps_or_is_overlapped(&b), b.overlaps_or_is_overlapped(&a));
+        }
+
+        #[test]
+        fn containment_implies_overlap(a in range(), b in range()) {
+            prop_assert!(!a.contains_or_is_contained(&b) || a.overlaps_or_is_overlapped(&b));
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Crane models, each its own way of carrying out an instruction.
+
+use std::num::NonZeroUsize;
+
+use crate::{Instruction, MoveError, Piles};
+
+pub trait Crane {
+    fn name(&self) -> String;
+
+    /// Carries out `ins`, leaving `piles` untouched if it can't.
+    fn apply(&self, piles: &mut Piles, ins: &Instruction) -> Result<(), MoveError>;
+}
+
+/// Moves crates one at a time, reversing their order.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> String {
+        "CrateMover 9000".to_owned()
+    }
+
+    fn apply(&self, piles: &mut Piles, ins: &Instruction) -> Result<(), MoveError> {
+        Capacity(NonZeroUsize::MIN).apply(piles, ins)
+    }
+}
+
+/// Moves all the crates at once, keeping their order.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> String {
+        "CrateMover 9001".to_owned()
+    }
+
+    fn apply(&self, piles: &mut Piles, ins: &Instruction) -> Result<(), MoveError> {
+        piles.check(ins)?;
+        piles.lift(ins.src, ins.dst, ins.quantity);
+        Ok(())
+    }
+}
+
+/// Lifts at most this many crates at once, each lift keeping their order.
+/// A capacity of 1 is a CrateMover 9000.
+pub struct Capacity(pub NonZeroUsize);
+
+impl Crane for Capacity {
+    fn name(&self) -> String {
+        format!("crane lifting up to {}", self.0)
+    }
+
+    fn apply(&self, piles: &mut Piles, ins: &Instruction) -> Result<(), MoveError> {
+        piles.check(ins)?;
+        let mut left = ins.quantity;
+        while left > 0 {
+            let lift = left.min(self.0.get());
+            piles.lift(ins.src, ins.dst, lift);
+            left -= lift;
+        }
+        Ok(())
+    }
+}
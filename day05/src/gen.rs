@@ -0,0 +1,64 @@
+//! Random crate drawings and rearrangement procedures.
+
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Up to nine piles and `size` moves. No move ever empties a pile, so
+/// both cranes end up with a crate on top of each.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let letters: Vec<char> = ('A'..='Z').collect();
+    let mut piles: Vec<Vec<char>> = (0..rng.gen_range(2..=9))
+        .map(|_| {
+            (0..rng.gen_range(2..=8))
+                .map(|_| *letters.choose(&mut rng).unwrap())
+                .collect()
+        })
+        .collect();
+
+    let mut out = drawing(&piles);
+    out.push('\n');
+
+    let mut moves = 0;
+    while moves < size {
+        let src = rng.gen_range(0..piles.len());
+        let dst = rng.gen_range(0..piles.len());
+        if src == dst || piles[src].len() < 2 {
+            continue;
+        }
+        let quantity = rng.gen_range(1..piles[src].len());
+        let at = piles[src].len() - quantity;
+        let moved: Vec<_> = piles[src].drain(at..).collect();
+        piles[dst].extend(moved);
+
+        out.push_str(&format!(
+            "move {quantity} from {} to {}\n",
+            src + 1,
+            dst + 1
+        ));
+        moves += 1;
+    }
+
+    out
+}
+
+fn drawing(piles: &[Vec<char>]) -> String {
+    let height = piles.iter().map(Vec::len).max().unwrap_or(0);
+    let mut out = String::new();
+    for level in (0..height).rev() {
+        let row: Vec<String> = piles
+            .iter()
+            .map(|pile| match pile.get(level) {
+                Some(c) => format!("[{c}]"),
+                None => "   ".to_owned(),
+            })
+            .collect();
+        out.push_str(&row.join(" "));
+        out.push('\n');
+    }
+    let numbers: Vec<String> = (1..=piles.len()).map(|i| format!(" {i} ")).collect();
+    out.push_str(&numbers.join(" "));
+    out.push('\n');
+    out
+}
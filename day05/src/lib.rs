@@ -0,0 +1,380 @@
+// TODO: take this back up from 'Heap allocation hate club'
+use std::fmt::{self};
+
+use aoc_core::{
+    parse::{self, token, PResult, ParseError},
+    Answer, Solution,
+};
+use color_eyre::eyre::eyre;
+use nom::{
+    branch::alt,
+    bytes::complete::take,
+    character::complete::{space0, space1},
+    combinator::{map, opt, verify},
+    error::context,
+    multi::separated_list1,
+    sequence::{delimited, preceded, tuple},
+};
+
+mod crane;
+pub mod gen;
+
+pub use crane::{Capacity, Crane, CrateMover9000, CrateMover9001};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Crate(char);
+
+impl fmt::Debug for Crate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for Crate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Stacks of crates, bottom first.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Piles(Vec<Vec<Crate>>);
+
+impl fmt::Debug for Piles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, pile) in self.0.iter().enumerate() {
+            writeln!(f, "Pile {}: {:?}", i, pile)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Piles are numbered from 1, as in the drawing.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MoveError {
+    #[error("there is no pile {pile}")]
+    NoSuchPile { pile: usize },
+    #[error("can't move {quantity} crates from pile {pile}, which only holds {available}")]
+    NotEnoughCrates {
+        quantity: usize,
+        pile: usize,
+        available: usize,
+    },
+}
+
+impl Piles {
+    /// Whether `ins` only moves crates that are there.
+    pub fn check(&self, ins: &Instruction) -> Result<(), MoveError> {
+        for pile in [ins.src, ins.dst] {
+            if pile >= self.0.len() {
+                return Err(MoveError::NoSuchPile { pile: pile + 1 });
+            }
+        }
+        let available = self.0[ins.src].len();
+        if ins.quantity > available {
+            return Err(MoveError::NotEnoughCrates {
+                quantity: ins.quantity,
+                pile: ins.src + 1,
+                available,
+            });
+        }
+        Ok(())
+    }
+
+    /// Moves the top `quantity` crates of pile `src` onto pile `dst` in one
+    /// go, keeping their order. Both piles are numbered from 0.
+    pub(crate) fn lift(&mut self, src: usize, dst: usize, quantity: usize) {
+        let pile = &mut self.0[src];
+        let lifted = pile.split_off(pile.len() - quantity);
+        self.0[dst].extend(lifted);
+    }
+
+    /// The crate on top of each pile, with a space for empty piles.
+    pub fn tops(&self) -> String {
+        self.0
+            .iter()
+            .map(|pile| pile.last().map_or(' ', |krate| krate.0))
+            .collect()
+    }
+}
+
+fn parse_crate(i: &str) -> PResult<'_, Crate> {
+    let first_char = |s: &str| Crate(s.chars().next().unwrap());
+    let f = delimited(token("["), take(1_usize), token("]"));
+
+    map(f, first_char)(i)
+}
+
+fn parse_hole(i: &str) -> PResult<'_, ()> {
+    map(token("   "), drop)(i)
+}
+
+fn parse_crate_or_hole(i: &str) -> PResult<'_, Option<Crate>> {
+    alt((map(parse_crate, Some), map(parse_hole, |_| None)))(i)
+}
+
+fn parse_crate_line(i: &str) -> PResult<'_, Vec<Option<Crate>>> {
+    let (mut i, c) = parse_crate_or_hole(i)?;
+    let mut v = vec![c];
+
+    loop {
+        let (next_i, maybe_c) = opt(preceded(token(" "), parse_crate_or_hole))(i)?;
+        match maybe_c {
+            Some(c) => v.push(c),
+            None => break,
+        }
+        i = next_i
+    }
+
+    Ok((i, v))
+}
+
+fn transpose_rev<T>(v: Vec<Vec<Option<T>>>) -> Vec<Vec<T>> {
+    let len = v.iter().map(Vec::len).max().unwrap_or(0);
+    let mut iters: Vec<_> = v.into_iter().map(|n| n.into_iter()).collect();
+    (0..len)
+        .map(|_| {
+            iters
+                .iter_mut()
+                .rev()
+                // lines may stop short of the last piles if they are empty
+                .filter_map(|n| n.next().flatten())
+                .collect::<Vec<T>>()
+        })
+        .collect()
+}
+
+fn parse_pile_number(i: &str) -> PResult<'_, usize> {
+    context(
+        "pile number",
+        map(verify(parse::number::<usize>, |&n| n > 0), |n| n - 1),
+    )(i)
+}
+
+/// The ` 1   2   3 ` line under the drawing.
+fn parse_pile_numbers(i: &str) -> PResult<'_, Vec<usize>> {
+    delimited(space0, separated_list1(space1, parse_pile_number), space0)(i)
+}
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    pub quantity: usize,
+    /// Numbered from 0.
+    pub src: usize,
+    /// Numbered from 0.
+    pub dst: usize,
+}
+
+fn parse_instruction(i: &str) -> PResult<'_, Instruction> {
+    map(
+        tuple((
+            preceded(token("move "), parse::number),
+            preceded(token(" from "), parse_pile_number),
+            preceded(token(" to "), parse_pile_number),
+        )),
+        |(quantity, src, dst)| Instruction { quantity, src, dst },
+    )(i)
+}
+
+pub struct Procedure {
+    piles: Piles,
+    instructions: Vec<Instruction>,
+    /// Where the instructions start in the input.
+    first_line: usize,
+}
+
+impl Procedure {
+    /// The piles once `crane` has carried out every instruction.
+    pub fn run(&self, crane: &dyn Crane) -> color_eyre::Result<Piles> {
+        let mut piles = self.piles.clone();
+        aoc_core::trace!("{}\n{piles:?}", crane.name());
+
+        for (ins, line) in self.instructions.iter().zip(self.first_line..) {
+            crane
+                .apply(&mut piles, ins)
+                .map_err(|e| eyre!("line {line}: {e}"))?;
+            aoc_core::trace!("{ins:?}\n{piles:?}");
+        }
+
+        Ok(piles)
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Procedure;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        let located = |e| ParseError::new(input, e);
+
+        let mut sections = parse::sections(input);
+        let (Some(drawing), Some(procedure)) = (sections.next(), sections.next()) else {
+            return Err(eyre!(
+                "expected the crate drawing and the procedure, separated by a blank line"
+            ));
+        };
+        if let Some(rest) = sections.next() {
+            let expected = "the end of the input, not more moves after a blank line";
+            return Err(ParseError::at(input, rest, expected).into());
+        }
+
+        let (crate_lines, numbers_line) = drawing.rsplit_once('\n').unwrap_or(("", drawing));
+        let crate_lines = parse::lines(crate_lines, parse_crate_line).map_err(located)?;
+        let numbers = parse::line(numbers_line, parse_pile_numbers).map_err(located)?;
+
+        let mut piles = Piles(transpose_rev(crate_lines));
+        if piles.0.len() > numbers.len() {
+            return Err(ParseError::at(
+                input,
+                numbers_line,
+                format!("{} pile numbers", piles.0.len()),
+            )
+            .into());
+        }
+        piles.0.resize(numbers.len(), Vec::new());
+
+        let mut instructions = Vec::new();
+        for line in procedure.lines() {
+            let ins = parse::line(line, parse_instruction).map_err(located)?;
+            if ins.src.max(ins.dst) >= numbers.len() {
+                let expected = format!("piles between 1 and {}", numbers.len());
+                return Err(ParseError::at(input, line, expected).into());
+            }
+            instructions.push(ins);
+        }
+
+        // `procedure` is a slice of `input`
+        let offset = procedure.as_ptr() as usize - input.as_ptr() as usize;
+        Ok(Procedure {
+            piles,
+            instructions,
+            first_line: input[..offset].matches('\n').count() + 1,
+        })
+    }
+
+    /// The top crates once the CrateMover 9000 is done.
+    fn part1(procedure: &Self::Input) -> color_eyre::Result<Answer> {
+        Ok(procedure.run(&CrateMover9000)?.tops().into())
+    }
+
+    /// The top crates once the CrateMover 9001 is done.
+    fn part2(procedure: &Self::Input) -> color_eyre::Result<Answer> {
+        Ok(procedure.run(&CrateMover9001)?.tops().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use aoc_core::Solution;
+    use proptest::prelude::*;
+
+    use crate::{Capacity, Crane, Crate, CrateMover9000, CrateMover9001, Day5, Instruction, Piles};
+
+    fn piles() -> impl Strategy<Value = Piles> {
+        let pile = prop::collection::vec(prop::char::range('A', 'Z').prop_map(Crate), 0..8);
+        prop::collection::vec(pile, 1..6).prop_map(Piles)
+    }
+
+    /// Raw numbers, turned into a legal instruction by [`legal`].
+    fn moves() -> impl Strategy<Value = Vec<(usize, usize, usize)>> {
+        prop::collection::vec(any::<(usize, usize, usize)>(), 0..30)
+    }
+
+    /// An instruction that only moves crates that are there, or `None` if
+    /// the chosen source pile is empty.
+    fn legal(piles: &Piles, (src, dst, quantity): (usize, usize, usize)) -> Option<Instruction> {
+        let src = src % piles.0.len();
+        let available = piles.0[src].len();
+        (available > 0).then(|| Instruction {
+            quantity: 1 + quantity % available,
+            src,
+            dst: dst % piles.0.len(),
+        })
+    }
+
+    fn sorted_crates(piles: &Piles) -> Vec<Crate> {
+        let mut crates: Vec<_> = piles.0.iter().flatten().copied().collect();
+        crates.sort();
+        crates
+    }
+
+    #[test]
+    fn too_many_crates() {
+        let procedure =
+            Day5::parse("[A]\n 1 \n\nmove 1 from 1 to 1\nmove 2 from 1 to 1\n").unwrap();
+        let error = procedure.run(&CrateMover9001).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 5: can't move 2 crates from pile 1, which only holds 1"
+        );
+    }
+
+    #[test]
+    fn blank_line_in_procedure() {
+        let input = "[A] [B]\n 1   2 \n\nmove 1 from 1 to 2\n\nmove 2 from 2 to 1\n";
+        let error = Day5::parse(input).err().unwrap();
+        assert!(error
+            .to_string()
+            .starts_with("line 6, column 1: expected the end of the input"));
+    }
+
+    proptest! {
+        #[test]
+        fn crates_are_conserved(mut piles in piles(), moves in moves()) {
+            let crates = sorted_crates(&piles);
+            let mut other = piles.clone();
+            for m in moves {
+                let Some(ins) = legal(&piles, m) else { continue };
+                CrateMover9000.apply(&mut piles, &ins).unwrap();
+                CrateMover9001.apply(&mut other, &ins).unwrap();
+                prop_assert_eq!(sorted_crates(&piles), crates.clone());
+                prop_assert_eq!(sorted_crates(&other), crates.clone());
+            }
+        }
+
+        #[test]
+        fn movers_agree_on_single_crates(mut piles in piles(), moves in moves()) {
+            let mut other = piles.clone();
+            for m in moves {
+                let Some(mut ins) = legal(&piles, m) else { continue };
+                ins.quantity = 1;
+                CrateMover9000.apply(&mut piles, &ins).unwrap();
+                CrateMover9001.apply(&mut other, &ins).unwrap();
+                prop_assert!(piles == other);
+            }
+        }
+
+        #[test]
+        fn all_at_once_keeps_order(piles in piles(), m in any::<(usize, usize, usize)>()) {
+            let Some(ins) = legal(&piles, m) else { return Ok(()) };
+            prop_assume!(ins.src != ins.dst);
+
+            let mut one_by_one = piles.clone();
+            CrateMover9000.apply(&mut one_by_one, &ins).unwrap();
+            let dst = &mut one_by_one.0[ins.dst];
+            let moved = dst.len() - ins.quantity;
+            dst[moved..].reverse();
+
+            let mut all_at_once = piles;
+            CrateMover9001.apply(&mut all_at_once, &ins).unwrap();
+            prop_assert!(one_by_one == all_at_once);
+        }
+
+        #[test]
+        fn capacity_spans_both_movers(piles in piles(), m in any::<(usize, usize, usize)>()) {
+            let Some(ins) = legal(&piles, m) else { return Ok(()) };
+            let after = |crane: &dyn Crane| {
+                let mut piles = piles.clone();
+                crane.apply(&mut piles, &ins).unwrap();
+                piles
+            };
+            let all = NonZeroUsize::new(ins.quantity).unwrap_or(NonZeroUsize::MIN);
+            prop_assert!(after(&Capacity(NonZeroUsize::MIN)) == after(&CrateMover9000));
+            prop_assert!(after(&Capacity(all)) == after(&CrateMover9001));
+        }
+    }
+}
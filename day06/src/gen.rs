@@ -0,0 +1,64 @@
+//! Random datastreams with markers where we want them.
+
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::SEQUENCE_SIZE;
+
+/// A stream of `size` letters (at least 32) with the start-of-message
+/// marker at a random position.
+pub fn generate(seed: u64, size: usize) -> String {
+    let len = size.max(32);
+    let marker = ChaCha8Rng::seed_from_u64(seed).gen_range(SEQUENCE_SIZE + 2..=len);
+    let mut stream = datastream(seed, len, marker);
+    stream.push('\n');
+    stream
+}
+
+/// A stream of `len` letters whose start-of-message marker ends at
+/// `marker`, which puts the start-of-packet marker at `marker - 10`.
+///
+/// # Panics
+///
+/// If `marker` isn't between 16 and `len`.
+pub fn datastream(seed: u64, len: usize, marker: usize) -> String {
+    assert!((SEQUENCE_SIZE + 2..=len).contains(&marker));
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let mut letters: Vec<u8> = (b'a'..=b'z').collect();
+    letters.shuffle(&mut rng);
+    let message = &letters[..SEQUENCE_SIZE];
+
+    // three letters can't hold a marker of either kind. Ending on the
+    // message's first letter twice means no window straddling the two
+    // halves is one either.
+    let first = message[0];
+    let few = [first, letters[SEQUENCE_SIZE], letters[SEQUENCE_SIZE + 1]];
+    let mut stream: Vec<u8> = (0..marker - SEQUENCE_SIZE - 2)
+        .map(|_| *few.choose(&mut rng).unwrap())
+        .collect();
+    stream.extend([first, first]);
+    stream.extend(message);
+    stream.extend((marker..len).map(|_| *letters.choose(&mut rng).unwrap()));
+
+    String::from_utf8(stream).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::{find_marker, message_start};
+
+    #[test_case(0, 16)]
+    #[test_case(1, 17)]
+    #[test_case(2, 500)]
+    #[test_case(3, 1000)]
+    fn markers_land_where_asked(seed: u64, marker: usize) {
+        let stream = super::datastream(seed, 1000, marker);
+        assert_eq!(stream.len(), 1000);
+        assert_eq!(find_marker(&stream, 4), Some(marker - 10));
+        assert_eq!(find_marker(&stream, 14), Some(marker));
+        assert_eq!(message_start(&stream), Some(marker));
+    }
+}
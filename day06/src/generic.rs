@@ -0,0 +1,83 @@
+//! The counting approach from the crate root, generic over the alphabet so
+//! the counters only take as much room as there are letters.
+
+const SEQUENCE_SIZE: usize = 14;
+
+trait Letter {
+    /// One counter per letter of the alphabet, e.g. `[u8; 26]`.
+    type Counts: AsRef<[u8]> + AsMut<[u8]> + Default;
+
+    fn to_usize(&self) -> usize;
+}
+
+impl Letter for u8 {
+    type Counts = [u8; 26];
+
+    fn to_usize(&self) -> usize {
+        assert!(self.is_ascii_lowercase());
+        *self as usize - b'a' as usize
+    }
+}
+
+struct State<L: Letter> {
+    data: L::Counts,
+}
+
+impl<L> Default for State<L>
+where
+    L: Letter,
+{
+    fn default() -> Self {
+        Self {
+            data: Default::default(),
+        }
+    }
+}
+
+impl<L> State<L>
+where
+    L: Letter,
+{
+    fn push(&mut self, c: L) {
+        let count = &mut self.data.as_mut()[c.to_usize()];
+        *count = count.checked_add(1).unwrap();
+    }
+
+    fn pop(&mut self, c: L) {
+        let count = &mut self.data.as_mut()[c.to_usize()];
+        *count = count.checked_sub(1).unwrap();
+    }
+
+    fn is_unique(&self) -> bool {
+        self.data.as_ref().iter().all(|&x| x <= 1)
+    }
+}
+
+pub fn marker_pos(input: &str) -> Option<usize> {
+    if input.len() < SEQUENCE_SIZE {
+        return None;
+    }
+
+    let mut state = State::<u8>::default();
+
+    input
+        .bytes()
+        .take(SEQUENCE_SIZE)
+        .for_each(|c| state.push(c));
+    if state.is_unique() {
+        return Some(SEQUENCE_SIZE);
+    }
+
+    for (index, window) in input.as_bytes().windows(SEQUENCE_SIZE + 1).enumerate() {
+        let removed = window[0];
+        let added = window[SEQUENCE_SIZE];
+
+        state.pop(removed);
+        state.push(added);
+
+        if state.is_unique() {
+            return Some(index + 1 + SEQUENCE_SIZE);
+        }
+    }
+    None
+}
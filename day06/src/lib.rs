@@ -0,0 +1,276 @@
+use aoc_core::{parse::ParseError, Answer, Part, Solution, Variant};
+
+pub mod gen;
+mod generic;
+
+pub use generic::marker_pos as generic_marker_pos;
+
+trait LowercaseLetter {
+    fn to_u32_for_bitset(&self) -> u32;
+}
+
+impl LowercaseLetter for u8 {
+    fn to_u32_for_bitset(&self) -> u32 {
+        assert!(self.is_ascii_lowercase());
+        1 << (*self as u32 - 'a' as u32)
+    }
+}
+
+/// Returns the position right after the first `size` distinct characters,
+/// checking each window with a bitset.
+pub fn find_marker(input: &str, size: usize) -> Option<usize> {
+    input
+        .as_bytes()
+        .windows(size)
+        .position(|window| {
+            window
+                .iter()
+                .map(|c| c.to_u32_for_bitset())
+                .fold(0, |acc, x| acc | x)
+                .count_ones() as usize
+                == size
+        })
+        .map(|pos| pos + size)
+}
+
+const SEQUENCE_SIZE: usize = 14;
+
+struct State {
+    data: [u8; 256],
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self { data: [0; 256] }
+    }
+}
+
+impl State {
+    fn push(&mut self, c: u8) {
+        self.data[c as usize] = self.data[c as usize].checked_add(1).unwrap();
+    }
+    fn pop(&mut self, c: u8) {
+        self.data[c as usize] = self.data[c as usize].checked_sub(1).unwrap();
+    }
+    fn is_unique(&self) -> bool {
+        self.data.iter().all(|&x| x <= 1)
+    }
+}
+
+/// Start-of-message marker, sliding a window of per-byte counts over the
+/// input instead of re-checking every window.
+pub fn marker_pos(input: &str) -> Option<usize> {
+    if input.len() < SEQUENCE_SIZE {
+        return None;
+    }
+
+    let mut state = State::default();
+    input
+        .bytes()
+        .take(SEQUENCE_SIZE)
+        .for_each(|c| state.push(c));
+
+    if state.is_unique() {
+        return Some(SEQUENCE_SIZE);
+    }
+
+    for (index, window) in input.as_bytes().windows(SEQUENCE_SIZE + 1).enumerate() {
+        let removed = window[0];
+        let added = window[SEQUENCE_SIZE];
+        state.pop(removed);
+        state.push(added);
+
+        if state.is_unique() {
+            return Some(index + 1 + SEQUENCE_SIZE);
+        }
+    }
+
+    None
+}
+
+fn is_unique(chars: &[char]) -> bool {
+    chars
+        .iter()
+        .enumerate()
+        .all(|(ix, c)| !chars[..ix].contains(c))
+}
+
+/// Start-of-message marker, keeping the previous 13 characters in a ring
+/// buffer.
+pub fn message_start(input: &str) -> Option<usize> {
+    const PREV_SIZE: usize = 13;
+
+    let chars: Vec<_> = input.chars().collect();
+    if chars.len() < PREV_SIZE {
+        return None;
+    }
+    let mut prev = [' '; PREV_SIZE];
+    prev.copy_from_slice(&chars[..PREV_SIZE]);
+    for (ix, c) in input.chars().skip(PREV_SIZE).enumerate() {
+        if !prev.contains(&c) && is_unique(&prev) {
+            return Some(ix + PREV_SIZE + 1);
+        } else {
+            prev[ix % PREV_SIZE] = c;
+        }
+    }
+    None
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    /// The datastream, without its trailing newline.
+    type Input = String;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        let stream = input.trim_end();
+        if let Some(i) = stream.find(|c: char| !c.is_ascii_lowercase()) {
+            return Err(ParseError::at(input, &stream[i..], "a lowercase letter").into());
+        }
+        Ok(stream.to_owned())
+    }
+
+    /// Start-of-packet marker: four distinct characters.
+    fn part1(stream: &Self::Input) -> color_eyre::Result<Answer> {
+        find_marker(stream, 4)
+            .map(Answer::from)
+            .ok_or_else(|| color_eyre::eyre::eyre!("no start-of-packet marker"))
+    }
+
+    /// Start-of-message marker: fourteen distinct characters.
+    fn part2(stream: &Self::Input) -> color_eyre::Result<Answer> {
+        message_marker(find_marker(stream, SEQUENCE_SIZE))
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant {
+                part: Part::One,
+                name: "bitset",
+                solve: Self::part1,
+            },
+            Variant {
+                part: Part::Two,
+                name: "bitset",
+                solve: Self::part2,
+            },
+            Variant {
+                part: Part::Two,
+                name: "counting",
+                solve: |stream| message_marker(marker_pos(stream)),
+            },
+            Variant {
+                part: Part::Two,
+                name: "ring-buffer",
+                solve: |stream| message_marker(message_start(stream)),
+            },
+            Variant {
+                part: Part::Two,
+                name: "generic",
+                solve: |stream| message_marker(generic_marker_pos(stream)),
+            },
+        ]
+    }
+}
+
+fn message_marker(pos: Option<usize>) -> color_eyre::Result<Answer> {
+    pos.map(Answer::from)
+        .ok_or_else(|| color_eyre::eyre::eyre!("no start-of-message marker"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_core::{Part, Solution};
+    use proptest::prelude::*;
+    use test_case::test_case;
+
+    use crate::{find_marker, generic_marker_pos, marker_pos, message_start, Day6};
+
+    #[test_case(7, "mjqjpqmgbljsphdztnvjfqwrcgsmlb")]
+    #[test_case(5, "bvwbjplbgvbhsrlpgdmjqwftvncz")]
+    #[test_case(6, "nppdvjthqldpwncqszvftbrmjlhg")]
+    #[test_case(10, "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg")]
+    #[test_case(11, "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")]
+    fn test_find_marker(index: usize, input: &str) {
+        assert_eq!(Some(index), find_marker(input, 4));
+    }
+
+    #[test_case(19, "mjqjpqmgbljsphdztnvjfqwrcgsmlb")]
+    #[test_case(23, "bvwbjplbgvbhsrlpgdmjqwftvncz")]
+    #[test_case(23, "nppdvjthqldpwncqszvftbrmjlhg")]
+    #[test_case(29, "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg")]
+    #[test_case(26, "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")]
+    fn test_message_marker(index: usize, input: &str) {
+        assert_eq!(find_marker(input, 14), Some(index));
+        assert_eq!(marker_pos(input), Some(index));
+        assert_eq!(generic_marker_pos(input), Some(index));
+        assert_eq!(message_start(input), Some(index));
+    }
+
+    /// The obvious way: the first window whose characters are all distinct.
+    fn naive_marker(input: &str, size: usize) -> Option<usize> {
+        input
+            .as_bytes()
+            .windows(size)
+            .position(|w| w.iter().collect::<HashSet<_>>().len() == size)
+            .map(|pos| pos + size)
+    }
+
+    #[test]
+    fn positioned_errors() {
+        let error = Day6::parse("abcDef\n").unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "line 1, column 4: expected a lowercase letter\n  |\n1 | abcDef\n  |    ^"
+        );
+    }
+
+    #[test]
+    fn variants_never_panic() {
+        for stream in [
+            "abcdefghijklmn",
+            "abcdefghijklm",
+            "",
+            "aaaaaaaaaaaaaaaaaaaa",
+        ] {
+            for variant in Day6::variants().iter().filter(|v| v.part == Part::Two) {
+                let answer = (variant.solve)(&stream.to_owned());
+                assert_eq!(
+                    answer.is_ok(),
+                    stream.len() == 14,
+                    "{} on {stream:?}",
+                    variant.name
+                );
+            }
+        }
+    }
+
+    proptest! {
+        // a small alphabet makes markers rare, the suffix guarantees one
+        #[test]
+        fn message_markers_agree(stream in "[a-r]{1,200}abcdefghijklmn") {
+            let expected = naive_marker(&stream, 14);
+            prop_assert!(expected.is_some());
+            prop_assert_eq!(find_marker(&stream, 14), expected);
+            prop_assert_eq!(marker_pos(&stream), expected);
+            prop_assert_eq!(generic_marker_pos(&stream), expected);
+            prop_assert_eq!(message_start(&stream), expected);
+        }
+
+        #[test]
+        fn short_or_markerless_streams(stream in "[a-p]{0,30}") {
+            let expected = naive_marker(&stream, 14);
+            prop_assert_eq!(find_marker(&stream, 14), expected);
+            prop_assert_eq!(marker_pos(&stream), expected);
+            prop_assert_eq!(generic_marker_pos(&stream), expected);
+            prop_assert_eq!(message_start(&stream), expected);
+        }
+
+        #[test]
+        fn packet_markers_agree(stream in "[a-e]{0,100}", size in 1_usize..6) {
+            prop_assert_eq!(find_marker(&stream, size), naive_marker(&stream, size));
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! Random rope motions.
+
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// `size` motions of one to twenty steps.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    (0..size.max(1))
+        .map(|_| {
+            let dir = ['U', 'D', 'L', 'R'].choose(&mut rng).unwrap();
+            format!("{dir} {}\n", rng.gen_range(1..=20))
+        })
+        .collect()
+}
@@ -0,0 +1,96 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_core::{
+    parse::{parse_lines, ParseError},
+    Answer, Solution,
+};
+use parse::{GridPos, Instruction};
+
+pub mod gen;
+pub mod parse;
+
+pub struct Rope {
+    pub instructions: VecDeque<Instruction>,
+    pub head: GridPos,
+    pub tail: GridPos,
+    pub tail_visited: HashSet<GridPos>,
+}
+
+impl Rope {
+    pub fn new(instructions: &[Instruction]) -> Self {
+        Self {
+            instructions: instructions.iter().copied().collect(),
+            head: GridPos { x: 0, y: 0 },
+            tail: GridPos { x: 0, y: 0 },
+            tail_visited: Default::default(),
+        }
+    }
+
+    /// Moves the head by a single step, dragging the tail along. Returns
+    /// `false` once every instruction has been carried out.
+    pub fn step(&mut self) -> bool {
+        let instruction = match self.instructions.front_mut() {
+            Some(instruction) => instruction,
+            None => return false,
+        };
+        self.head += instruction.dir.delta();
+
+        let diff = self.head - self.tail;
+
+        let (dx, dy) = match (diff.x, diff.y) {
+            (0, 0) => (0, 0),
+            // touching up/left/down/right
+            (0, 1) | (1, 0) | (0, -1) | (-1, 0) => (0, 0),
+            // touching diagonally
+            (1, 1) | (1, -1) | (-1, 1) | (-1, -1) => (0, 0),
+            // need to move up/left/down/right
+            (0, 2) => (0, 1),
+            (0, -2) => (0, -1),
+            (2, 0) => (1, 0),
+            (-2, 0) => (-1, 0),
+            // need to move to the right diagonally
+            (2, 1) => (1, 1),
+            (2, -1) => (1, -1),
+            // need to move to the left diagonally
+            (-2, 1) => (-1, 1),
+            (-2, -1) => (-1, -1),
+            // need to move up/down diagonally
+            (1, 2) => (1, 1),
+            (-1, 2) => (-1, 1),
+            (1, -2) => (1, -1),
+            (-1, -2) => (-1, -1),
+            _ => panic!("unhandled case: tail - head = {diff:?}"),
+        };
+
+        self.tail.x += dx;
+        self.tail.y += dy;
+        self.tail_visited.insert(self.tail);
+
+        instruction.dist -= 1;
+        if instruction.dist == 0 {
+            self.instructions.pop_front();
+        }
+        true
+    }
+}
+
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, Instruction::parse)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(parse_instructions(input)?)
+    }
+
+    /// Counts the positions the tail visits at least once.
+    fn part1(instructions: &Self::Input) -> color_eyre::Result<Answer> {
+        let mut rope = Rope::new(instructions);
+        while rope.step() {}
+        Ok(rope.tail_visited.len().into())
+    }
+}
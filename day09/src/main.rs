@@ -1,13 +1,14 @@
-use std::{collections::VecDeque, time::Duration};
+use std::time::Duration;
 
-use eframe::{egui, epaint::ahash::HashSet};
-use egui::{Color32, Sense, Stroke};
-use nom::{combinator::all_consuming, Finish};
-use parse::{Direction, Instruction};
-
-use crate::parse::GridPos;
+#[cfg(not(target_arch = "wasm32"))]
+use aoc_core::input::Source;
 
-mod parse;
+use day09::{
+    parse::{Direction, GridPos, Instruction},
+    parse_instructions, Rope,
+};
+use eframe::egui;
+use egui::{Color32, Sense, Stroke};
 
 #[cfg(target_arch = "wasm32")]
 fn main() {
@@ -21,7 +22,12 @@ fn main() {
         eframe::start_web(
             "canvas",
             web_options,
-            Box::new(|_cc| Box::new(MyApp::new())),
+            // there's no filesystem to load from in the browser
+            Box::new(|_cc| {
+                let instructions = parse_instructions(include_str!("sample_input.txt"))
+                    .expect("the bundled sample is well-formed");
+                Box::new(MyApp::new(&instructions))
+            }),
         )
         .await
         .expect("failed to start eframe");
@@ -29,7 +35,16 @@ fn main() {
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-fn main() {
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    // `day09 [PATH|-]`, showing the sample motions by default
+    let source: Source = std::env::args().nth(1).map_or_else(
+        || Source::Path(concat!(env!("CARGO_MANIFEST_DIR"), "/src/sample_input.txt").into()),
+        |arg| arg.parse().unwrap(),
+    );
+    let instructions = parse_instructions(&source.load()?)?;
+
     let options = eframe::NativeOptions {
         initial_window_size: Some(egui::vec2(800.0, 600.0)),
         ..Default::default()
@@ -37,87 +52,36 @@ fn main() {
     eframe::run_native(
         "AoC 2022 - Day 9",
         options,
-        Box::new(|_cc| Box::new(MyApp::new())),
+        Box::new(move |_cc| Box::new(MyApp::new(&instructions))),
     );
+    Ok(())
 }
 
 struct MyApp {
-    instructions: VecDeque<Instruction>,
-    head: GridPos,
-    tail: GridPos,
-    tail_visited: HashSet<GridPos>,
+    rope: Rope,
 }
 
 impl MyApp {
-    fn new() -> Self {
-        let instructions = include_str!("sample-input.txt")
-            .lines()
-            .map(|l| all_consuming(Instruction::parse)(l).finish().unwrap().1)
-            .collect();
-
+    fn new(instructions: &[Instruction]) -> Self {
         Self {
-            instructions,
-            head: GridPos { x: 0, y: 0 },
-            tail: GridPos { x: 0, y: 0 },
-            tail_visited: Default::default(),
-        }
-    }
-
-    fn update_state(&mut self) {
-        let instruction = match self.instructions.front_mut() {
-            Some(instruction) => instruction,
-            None => return,
-        };
-        self.head += instruction.dir.delta();
-
-        let diff = self.head - self.tail;
-
-        let (dx, dy) = match (diff.x, diff.y) {
-            (0, 0) => (0, 0),
-            // touching up/left/down/right
-            (0, 1) | (1, 0) | (0, -1) | (-1, 0) => (0, 0),
-            // touching diagonally
-            (1, 1) | (1, -1) | (-1, 1) | (-1, -1) => (0, 0),
-            // need to move up/left/down/right
-            (0, 2) => (0, 1),
-            (0, -2) => (0, -1),
-            (2, 0) => (1, 0),
-            (-2, 0) => (-1, 0),
-            // need to move to the right diagonally
-            (2, 1) => (1, 1),
-            (2, -1) => (1, -1),
-            // need to move to the left diagonally
-            (-2, 1) => (-1, 1),
-            (-2, -1) => (-1, -1),
-            // need to move up/down diagonally
-            (1, 2) => (1, 1),
-            (-1, 2) => (-1, 1),
-            (1, -2) => (1, -1),
-            (-1, -2) => (-1, -1),
-            _ => panic!("unhandled case: tail - head = {diff:?}"),
-        };
-
-        self.tail.x += dx;
-        self.tail.y += dy;
-        self.tail_visited.insert(self.tail);
-
-        instruction.dist -= 1;
-        if instruction.dist == 0 {
-            self.instructions.pop_front();
+            rope: Rope::new(instructions),
         }
     }
 }
 
 impl eframe::App for MyApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        self.update_state();
+        self.rope.step();
 
         egui::SidePanel::left("side_panel").show(ctx, |ui| {
-            ui.label(format!("{} instructions left", self.instructions.len()));
-            ui.label(format!("{} places visited", self.tail_visited.len()));
+            ui.label(format!(
+                "{} instructions left",
+                self.rope.instructions.len()
+            ));
+            ui.label(format!("{} places visited", self.rope.tail_visited.len()));
 
             egui::ScrollArea::new([false, true]).show(ui, |ui| {
-                for ins in &self.instructions {
+                for ins in &self.rope.instructions {
                     let arrow = match ins.dir {
                         Direction::Up => "⬆",
                         Direction::Down => "⬇",
@@ -147,7 +111,7 @@ impl eframe::App for MyApp {
             for x in -half_width..half_width {
                 for y in -half_height..half_height {
                     let dot = GridPos { x, y };
-                    if !self.tail_visited.contains(&dot) {
+                    if !self.rope.tail_visited.contains(&dot) {
                         continue;
                     }
                     let color = Color32::DARK_RED;
@@ -157,10 +121,10 @@ impl eframe::App for MyApp {
                 }
             }
 
-            let head_pos = to_panel_pos(self.head);
+            let head_pos = to_panel_pos(self.rope.head);
             painter.circle_stroke(head_pos, 2.0, Stroke::new(2.0, Color32::GREEN));
 
-            let tail_pos = to_panel_pos(self.tail);
+            let tail_pos = to_panel_pos(self.rope.tail);
             painter.circle_stroke(tail_pos, 2.0, Stroke::new(2.0, Color32::YELLOW));
 
             painter.arrow(
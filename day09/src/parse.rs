@@ -1,58 +1,16 @@
+use aoc_core::parse::{number, token, PResult};
 use nom::{
     branch::alt,
-    bytes::complete::tag,
     character::complete::space1,
-    combinator::{map, value},
+    combinator::{map, value, verify},
+    error::context,
     sequence::{preceded, tuple},
-    IResult,
 };
-use std::fmt;
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-pub(crate) struct GridPos {
-    pub(crate) x: i32,
-    pub(crate) y: i32,
-}
-
-impl fmt::Debug for GridPos {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
-    }
-}
-
-impl std::ops::Add for GridPos {
-    type Output = GridPos;
-
-    fn add(self, other: Self) -> Self::Output {
-        GridPos {
-            x: self.x + other.x,
-            y: self.y + other.y,
-        }
-    }
-}
-
-impl std::ops::AddAssign for GridPos {
-    fn add_assign(&mut self, other: Self) {
-        *self = GridPos {
-            x: self.x + other.x,
-            y: self.y + other.y,
-        }
-    }
-}
-
-impl std::ops::Sub for GridPos {
-    type Output = GridPos;
-
-    fn sub(self, other: Self) -> Self::Output {
-        GridPos {
-            x: self.x - other.x,
-            y: self.y - other.y,
-        }
-    }
-}
+pub use aoc_grid::GridPos;
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-pub(crate) enum Direction {
+pub enum Direction {
     Up,
     Down,
     Left,
@@ -60,39 +18,54 @@ pub(crate) enum Direction {
 }
 
 impl Direction {
-    pub(crate) fn parse(i: &str) -> IResult<&str, Self> {
+    pub fn parse(i: &str) -> PResult<'_, Self> {
         alt((
-            value(Direction::Up, tag("U")),
-            value(Direction::Down, tag("D")),
-            value(Direction::Left, tag("L")),
-            value(Direction::Right, tag("R")),
+            value(Direction::Up, token("U")),
+            value(Direction::Down, token("D")),
+            value(Direction::Left, token("L")),
+            value(Direction::Right, token("R")),
         ))(i)
     }
 
-    pub(crate) fn delta(self) -> GridPos {
+    pub fn delta(self) -> GridPos {
         match self {
-            Direction::Up => GridPos { x: 0, y: -1 },
-            Direction::Down => GridPos { x: 0, y: 1 },
-            Direction::Left => GridPos { x: -1, y: 0 },
-            Direction::Right => GridPos { x: 1, y: 0 },
+            Direction::Up => GridPos::UP,
+            Direction::Down => GridPos::DOWN,
+            Direction::Left => GridPos::LEFT,
+            Direction::Right => GridPos::RIGHT,
         }
     }
 }
 
 #[derive(Debug, Clone, Copy)]
-pub(crate) struct Instruction {
-    pub(crate) dir: Direction,
-    pub(crate) dist: u32,
+pub struct Instruction {
+    pub dir: Direction,
+    pub dist: u32,
 }
 
 impl Instruction {
-    pub(crate) fn parse(i: &str) -> IResult<&str, Self> {
+    pub fn parse(i: &str) -> PResult<'_, Self> {
         map(
             tuple((
                 Direction::parse,
-                preceded(space1, nom::character::complete::u32),
+                preceded(
+                    space1,
+                    context("distance of at least 1", verify(number, |&d| d > 0)),
+                ),
             )),
             |(dir, dist)| Self { dir, dist },
         )(i)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_instructions;
+
+    #[test]
+    fn zero_distance() {
+        let error = parse_instructions("R 4\nU 0\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.expected, "distance of at least 1");
+    }
+}
@@ -0,0 +1,66 @@
+//! Random terminal sessions exploring a random directory tree.
+
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+#[derive(Default)]
+struct Dir {
+    files: Vec<(String, u64)>,
+    /// Indices of the subdirectories.
+    dirs: Vec<usize>,
+}
+
+/// A tree of `size` directories, listed and walked depth first.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    // each new directory goes under a random earlier one, which keeps the
+    // tree shallow
+    let mut dirs: Vec<Dir> = vec![Dir::default()];
+    for index in 1..size.max(1) {
+        let parent = rng.gen_range(0..index);
+        dirs[parent].dirs.push(index);
+        dirs.push(Dir::default());
+    }
+    for dir in &mut dirs {
+        dir.files = (0..rng.gen_range(0..=4))
+            .map(|_| {
+                let ext = ["", ".txt", ".dat", ".lst"].choose(&mut rng).unwrap();
+                (ext.to_string(), rng.gen_range(1..=300_000))
+            })
+            .collect();
+    }
+
+    let mut out = "$ cd /\n".to_owned();
+    transcript(&dirs, 0, &mut out);
+    out
+}
+
+/// A short lowercase name, distinct for every `n`.
+fn name(mut n: usize) -> String {
+    let mut name = String::new();
+    loop {
+        name.push((b'a' + (n % 26) as u8) as char);
+        n /= 26;
+        if n == 0 {
+            return name;
+        }
+    }
+}
+
+fn transcript(dirs: &[Dir], index: usize, out: &mut String) {
+    // subdirectories are named first, then files, so names never clash
+    let dir = &dirs[index];
+    out.push_str("$ ls\n");
+    for n in 0..dir.dirs.len() {
+        out.push_str(&format!("dir {}\n", name(n)));
+    }
+    for (n, (ext, size)) in dir.files.iter().enumerate() {
+        out.push_str(&format!("{size} {}{ext}\n", name(dir.dirs.len() + n)));
+    }
+    for (n, &child) in dir.dirs.iter().enumerate() {
+        out.push_str(&format!("$ cd {}\n", name(n)));
+        transcript(dirs, child, out);
+        out.push_str("$ cd ..\n");
+    }
+}
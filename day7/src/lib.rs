@@ -1,33 +1,34 @@
 use core::fmt;
-use std::{
-    cell::RefCell,
-    collections::{BTreeMap, HashMap},
-    fmt::Debug,
-    rc::Rc,
-};
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
 
+use aoc_core::{
+    parse::{self, token, PResult, ParseError},
+    Answer, Solution,
+};
 use camino::Utf8PathBuf;
 use indexmap::IndexMap;
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_while1},
-    combinator::{all_consuming, map},
+    bytes::complete::take_while1,
+    combinator::map,
+    error::context,
     sequence::{preceded, separated_pair},
-    Finish, IResult,
 };
 
+pub mod gen;
+
 #[derive(Debug)]
 struct Ls;
 
-fn parse_ls(i: &str) -> IResult<&str, Ls> {
-    map(tag("ls"), |_| Ls)(i)
+fn parse_ls(i: &str) -> PResult<'_, Ls> {
+    map(token("ls"), |_| Ls)(i)
 }
 
 #[derive(Debug)]
 struct Cd(Utf8PathBuf);
 
-fn parse_cd(i: &str) -> IResult<&str, Cd> {
-    map(preceded(tag("cd "), parse_path), Cd)(i)
+fn parse_cd(i: &str) -> PResult<'_, Cd> {
+    map(preceded(token("cd "), parse_path), Cd)(i)
 }
 
 #[derive(Debug)]
@@ -48,8 +49,8 @@ impl From<Cd> for Command {
     }
 }
 
-fn parse_command(i: &str) -> IResult<&str, Command> {
-    let (i, _) = tag("$ ")(i)?;
+fn parse_command(i: &str) -> PResult<'_, Command> {
+    let (i, _) = token("$ ")(i)?;
     alt((map(parse_ls, Into::into), map(parse_cd, Into::into)))(i)
 }
 
@@ -59,13 +60,13 @@ enum Entry {
     File(u64, Utf8PathBuf),
 }
 
-fn parse_entry(i: &str) -> IResult<&str, Entry> {
+fn parse_entry(i: &str) -> PResult<'_, Entry> {
     let parse_file = map(
-        separated_pair(nom::character::complete::u64, tag(" "), parse_path),
+        separated_pair(parse::number, token(" "), parse_path),
         |(size, path)| Entry::File(size, path),
     );
 
-    let parse_dir = map(preceded(tag("dir "), parse_path), Entry::Dir);
+    let parse_dir = map(preceded(token("dir "), parse_path), Entry::Dir);
 
     alt((parse_file, parse_dir))(i)
 }
@@ -76,17 +77,20 @@ enum Line {
     Entry(Entry),
 }
 
-fn parse_line(i: &str) -> IResult<&str, Line> {
+fn parse_line(i: &str) -> PResult<'_, Line> {
     alt((
         map(parse_command, Line::Command),
         map(parse_entry, Line::Entry),
     ))(i)
 }
 
-fn parse_path(i: &str) -> IResult<&str, Utf8PathBuf> {
-    map(
-        take_while1(|c: char| "abcdefghijklmnopqrstuvwxwy./".contains(c)),
-        Into::into,
+fn parse_path(i: &str) -> PResult<'_, Utf8PathBuf> {
+    context(
+        "path",
+        map(
+            take_while1(|c: char| "abcdefghijklmnopqrstuvwxyz./".contains(c)),
+            Into::into,
+        ),
     )(i)
 }
 
@@ -164,16 +168,18 @@ impl fmt::Debug for Node {
     }
 }
 
-fn main() {
-    let lines = include_str!("sample_input.txt")
-        .lines()
-        .map(|l| all_consuming(parse_line)(l).finish().unwrap().1);
+/// The directory tree rebuilt from the terminal output.
+pub struct FileSystem {
+    root: NodeHandle,
+}
 
+fn parse_tree(input: &str) -> Result<NodeHandle, ParseError> {
     let root = Rc::new(RefCell::new(Node::default()));
 
     let mut node = root.clone();
 
-    for line in lines {
+    for text in input.lines() {
+        let line = parse::line(text, parse_line).map_err(|e| ParseError::new(input, e))?;
         match line {
             Line::Command(cmd) => match cmd {
                 Command::Ls => {
@@ -182,8 +188,10 @@ fn main() {
                 Command::Cd(path) => match path.as_str() {
                     "/" => {}
                     ".." => {
-                        let parent = node.borrow().parent.clone().unwrap();
-                        node = parent;
+                        let parent = node.borrow().parent.clone();
+                        node = parent.ok_or_else(|| {
+                            ParseError::at(input, text, "a directory to leave, not the root")
+                        })?;
                     }
                     _ => {
                         let child = node.borrow_mut().children.entry(path).or_default().clone();
@@ -205,13 +213,27 @@ fn main() {
         }
     }
 
-    let sum = all_dirs(root)
-        .map(|d| d.borrow().total_size())
-        .filter(|&s| s <= 100_000)
-        .inspect(|s| {
-            dbg!(s);
-        })
-        .sum::<u64>();
+    Ok(root)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = FileSystem;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        let root = parse_tree(input)?;
+        aoc_core::trace!("{:?}", PrettyNode(&root));
+        Ok(FileSystem { root })
+    }
 
-    dbg!(sum);
+    /// Sums the total sizes of every directory holding at most 100000.
+    fn part1(fs: &Self::Input) -> color_eyre::Result<Answer> {
+        Ok(all_dirs(fs.root.clone())
+            .map(|d| d.borrow().total_size())
+            .filter(|&s| s <= 100_000)
+            .inspect(|s| aoc_core::trace!("small directory: {s}"))
+            .sum::<u64>()
+            .into())
+    }
 }
@@ -0,0 +1,19 @@
+//! Random tree height maps.
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// A `size` by `size` grid of heights.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            let mut row: String = (0..size)
+                .map(|_| char::from_digit(rng.gen_range(0..10), 10).unwrap())
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
@@ -0,0 +1,39 @@
+use aoc_core::{Answer, Solution};
+use aoc_grid::{Grid, GridPos};
+
+pub mod gen;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    /// Tree heights.
+    type Input = Grid<usize>;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(parse_grid(input)?)
+    }
+
+    fn part1(grid: &Self::Input) -> color_eyre::Result<Answer> {
+        Ok(num_visible_cells(grid).into())
+    }
+}
+
+/// Counts the trees visible from outside the grid.
+fn num_visible_cells(grid: &Grid<usize>) -> usize {
+    grid.iter()
+        .filter(|&(coord, coord_height)| {
+            GridPos::NEIGHBOURS4.iter().any(|&delta| {
+                grid.ray(coord, delta)
+                    .all(|(_, height)| height < coord_height)
+            })
+        })
+        .count()
+}
+
+fn parse_grid(input: &str) -> Result<Grid<usize>, aoc_grid::ParseError<&'static str>> {
+    Grid::parse(input, |col| {
+        col.to_digit(10)
+            .map(|height| height as usize)
+            .ok_or("tree heights are single digits")
+    })
+}